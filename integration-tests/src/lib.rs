@@ -0,0 +1,6 @@
+//! The tests of the integration options, which live outside the workspace so that building and testing
+//! the workspace doesn't need the crates they generate code for. Enable the features to run them:
+//!
+//! ```sh
+//! cargo test --manifest-path integration-tests/Cargo.toml --all-features
+//! ```
@@ -0,0 +1,22 @@
+#![cfg(feature = "defmt")]
+
+use rotate_enum::RotateEnum;
+
+#[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+#[rotate(defmt)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+fn assert_format<T: defmt::Format>(value: T) -> T {
+    value
+}
+
+#[test]
+fn test_format() {
+    // Formatting needs a global logger, so the test only checks that the enum implements the trait.
+    assert_eq!(assert_format(Direction::Down).next(), Direction::Right);
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "enum-map")]
+
+use enum_map::{enum_map, Enum, EnumMap};
+use rotate_enum::RotateEnum;
+
+#[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+#[rotate(enum_map)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[test]
+fn test_enum_map() {
+    assert_eq!(Direction::LENGTH, 4);
+    assert_eq!(Direction::from_usize(2), Direction::Down);
+    assert_eq!(Direction::Right.into_usize(), 3);
+
+    let mut steps: EnumMap<Direction, u32> = enum_map! { _ => 0 };
+    steps[Direction::Left] += 1;
+    steps[Direction::Left.next()] += 2;
+    assert_eq!(steps.values().copied().collect::<Vec<_>>(), [0, 1, 2, 0]);
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "num-traits")]
+
+use num_traits::{Bounded, FromPrimitive, ToPrimitive};
+use rotate_enum::RotateEnum;
+
+#[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+#[rotate(num_traits)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+#[rotate(num_traits)]
+#[repr(i8)]
+enum Level {
+    Low = -1,
+    Mid = 0,
+    High = 1,
+}
+
+#[test]
+fn test_bounded() {
+    assert_eq!(Direction::min_value(), Direction::Up);
+    assert_eq!(Direction::max_value(), Direction::Right);
+}
+
+#[test]
+fn test_primitives() {
+    assert_eq!(Direction::from_u64(2), Some(Direction::Down));
+    assert_eq!(Direction::from_i64(-1), None);
+    assert_eq!(Direction::from_usize(4), None);
+    assert_eq!(Direction::Right.to_u8(), Some(3));
+    assert_eq!(Level::from_i64(-1), Some(Level::Low));
+    assert_eq!(Level::Low.to_i64(), Some(-1));
+    assert_eq!(Level::Low.to_u64(), None);
+}
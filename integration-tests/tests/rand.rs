@@ -0,0 +1,52 @@
+#![cfg(feature = "rand")]
+
+use rand::{rngs::StdRng, SeedableRng};
+use rotate_enum::{EnumTools, RotateEnum};
+
+#[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+#[rotate(rand)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate)]
+#[rotate(rand)]
+enum Loot {
+    #[weight(10)]
+    Coin,
+    #[weight(0)]
+    Nothing,
+    #[weight(3)]
+    Potion,
+}
+
+#[test]
+fn test_random_step() {
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..100 {
+        let direction = Direction::Down.random_step(&mut rng);
+        assert!(direction == Direction::Left || direction == Direction::Right);
+    }
+}
+
+#[test]
+fn test_shuffled_iter() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut indices = Direction::shuffled_iter(&mut rng)
+        .map(|direction| direction.index())
+        .collect::<Vec<_>>();
+    indices.sort_unstable();
+    assert_eq!(indices, [0, 1, 2, 3]);
+}
+
+#[test]
+fn test_random_weighted() {
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..100 {
+        assert_ne!(Loot::random_weighted(&mut rng), Loot::Nothing);
+    }
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "rkyv")]
+
+use rkyv::{Deserialize, Fallible};
+use rotate_enum::RotateEnum;
+
+#[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+#[rotate(rkyv)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+/// A deserializer that reports the invalid indices.
+struct Strict;
+
+impl Fallible for Strict {
+    type Error = DirectionArchiveError;
+}
+
+#[test]
+fn test_round_trip() {
+    for index in 0..Direction::COUNT {
+        let direction = Direction::from_index(index).unwrap();
+        let bytes = rkyv::to_bytes::<_, 256>(&direction).unwrap();
+        let archived = unsafe { rkyv::archived_root::<Direction>(&bytes) };
+        assert_eq!(*archived as usize, index);
+        assert_eq!(
+            Deserialize::<Direction, _>::deserialize(archived, &mut Strict),
+            Ok(direction)
+        );
+    }
+}
+
+#[test]
+fn test_invalid_index() {
+    let bytes = rkyv::to_bytes::<_, 256>(&4u32).unwrap();
+    let archived = unsafe { rkyv::archived_root::<u32>(&bytes) };
+    assert_eq!(
+        Deserialize::<Direction, _>::deserialize(archived, &mut Strict),
+        Err(DirectionArchiveError { index: 4 })
+    );
+    assert_eq!(
+        DirectionArchiveError { index: 4 }.to_string(),
+        "invalid archived Direction index 4"
+    );
+}
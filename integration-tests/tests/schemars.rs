@@ -0,0 +1,32 @@
+#![cfg(feature = "schemars")]
+
+use rotate_enum::RotateEnum;
+use schemars::JsonSchema;
+
+#[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+#[rotate(schemars)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[test]
+fn test_schema() {
+    assert_eq!(Direction::schema_name(), "Direction");
+    let schema = schemars::schema_for!(Direction).schema;
+    assert_eq!(
+        schema.instance_type,
+        Some(schemars::schema::InstanceType::String.into())
+    );
+    assert_eq!(
+        schema.enum_values,
+        Some(vec![
+            "Up".into(),
+            "Left".into(),
+            "Down".into(),
+            "Right".into()
+        ])
+    );
+}
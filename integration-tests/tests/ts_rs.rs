@@ -0,0 +1,29 @@
+#![cfg(feature = "ts-rs")]
+
+use rotate_enum::RotateEnum;
+use ts_rs::TS;
+
+#[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+#[rotate(ts_rs)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[allow(dead_code)]
+#[derive(TS)]
+struct Player {
+    facing: Direction,
+}
+
+#[test]
+fn test_ts() {
+    assert_eq!(Direction::name(), "Direction");
+    assert_eq!(
+        Direction::decl(),
+        r#"type Direction = "Up" | "Left" | "Down" | "Right";"#
+    );
+    assert!(Player::decl().contains("facing: Direction"));
+}
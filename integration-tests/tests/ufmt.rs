@@ -0,0 +1,37 @@
+#![cfg(feature = "ufmt")]
+
+use core::convert::Infallible;
+use rotate_enum::RotateEnum;
+use ufmt::{uWrite, uwrite};
+
+#[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+#[rotate(ufmt)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+struct Buffer(String);
+
+impl uWrite for Buffer {
+    type Error = Infallible;
+    fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+        self.0.push_str(s);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_udisplay() {
+    let mut buffer = Buffer(String::new());
+    uwrite!(
+        &mut buffer,
+        "{} {}",
+        Direction::Down,
+        Direction::Down.next()
+    )
+    .unwrap();
+    assert_eq!(buffer.0, "Down Right");
+}
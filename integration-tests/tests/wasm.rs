@@ -0,0 +1,43 @@
+#![cfg(feature = "wasm-bindgen")]
+
+use rotate_enum::RotateEnum;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen]
+#[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+#[rotate(wasm_bindgen)]
+pub enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[test]
+fn test_functions() {
+    assert_eq!(direction_count(), 4);
+    assert_eq!(direction_index(Direction::Down), 2);
+    assert_eq!(direction_from_index(3), Some(Direction::Right));
+    assert_eq!(direction_next(Direction::Right), Direction::Up);
+    assert_eq!(direction_prev(Direction::Up), Direction::Right);
+}
+
+// The functions of a private enum are private too, or the private type would leak through them.
+mod private {
+    use rotate_enum::ShiftEnum;
+    use wasm_bindgen::prelude::wasm_bindgen;
+
+    #[wasm_bindgen]
+    #[derive(ShiftEnum, Clone, Copy, PartialEq, Debug)]
+    #[rotate(wasm_bindgen)]
+    enum Speed {
+        Slow,
+        Fast,
+    }
+
+    #[test]
+    fn test_private() {
+        assert_eq!(speed_next(Speed::Slow), Some(Speed::Fast));
+        assert_eq!(speed_prev(Speed::Slow), None);
+    }
+}
@@ -0,0 +1,65 @@
+//! Uses the macros and the runtime types in a `no_std` crate that doesn't link `alloc`,
+//! so that building the workspace fails if any of them starts to allocate.
+#![no_std]
+
+use core::sync::atomic::Ordering;
+use rotate_enum::{BounceEnum, EnumTools, IterEnum, RotateEnum, ShiftEnum};
+use rotate_enum_runtime::{AtomicEnum, CyclicIndex, EnumVariants, IntoEnumIterator, RoundRobin};
+
+#[derive(RotateEnum, IterEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime, table, default)]
+pub enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime, maps_to = "Vertical")]
+pub enum Speed {
+    Slow,
+    Fast,
+}
+
+#[derive(BounceEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+pub enum Level {
+    Low,
+    Middle,
+    High,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+#[enum_tools(rotate, iter, names)]
+#[subset(Vertical: Up, Down)]
+pub enum Heading {
+    #[value(0.0)]
+    #[key('w')]
+    Up,
+    #[value(90.0)]
+    #[key('d')]
+    Right,
+    #[value(180.0)]
+    #[key('s')]
+    Down,
+}
+
+pub fn use_everything() -> usize {
+    let direction = AtomicEnum::new(Direction::Up);
+    direction.fetch_next(Ordering::Relaxed);
+    let index = CyclicIndex::from(direction.load(Ordering::Relaxed)) + 3;
+    let mut scheduler = RoundRobin::<Direction>::new();
+    scheduler.remove(&Direction::from(index));
+    let table = DirectionTable::new([1, 2, 3, 4]);
+
+    let parsed = "Down".parse::<Heading>().ok();
+    table[scheduler.next().unwrap_or_default()]
+        + Direction::variants().len()
+        + <Direction as IntoEnumIterator>::iter().count()
+        + Speed::Slow.next().map_or(0, |speed| speed.index())
+        + Level::High.bounce(true).0.index()
+        + parsed.map_or(0, |heading| heading.iter().count())
+        + Vertical::from(Speed::Fast).next().index()
+}
@@ -0,0 +1,204 @@
+use crate::Rotate;
+use core::{
+    fmt,
+    marker::PhantomData,
+    sync::atomic::{AtomicU16, AtomicU32, AtomicU8, AtomicUsize, Ordering},
+};
+
+/// An unsigned integer type that holds the indices of an enum, see [`Rotate::Index`].
+///
+/// It is implemented for `u8`, `u16`, `u32` and `usize`, and picks the atomic type of the same size.
+pub trait IndexType {
+    /// The atomic integer of the same size, which backs [`AtomicEnum`].
+    type Atomic: AtomicIndex;
+}
+
+/// An atomic integer that stores an index, implemented for the atomic types of [`IndexType`].
+///
+/// Only `load()` and `store()` are needed by default. The methods that read and write in one operation
+/// need compare-and-swap instructions, which some targets like `thumbv6m-none-eabi` don't have,
+/// so they are behind the `cas` feature, which is enabled by default.
+pub trait AtomicIndex: Sized {
+    fn new(index: usize) -> Self;
+    fn into_inner(self) -> usize;
+    fn load(&self, order: Ordering) -> usize;
+    fn store(&self, index: usize, order: Ordering);
+    #[cfg(feature = "cas")]
+    fn compare_exchange(
+        &self,
+        current: usize,
+        new: usize,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<usize, usize>;
+    #[cfg(feature = "cas")]
+    fn fetch_update(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        f: impl FnMut(usize) -> usize,
+    ) -> usize;
+}
+
+macro_rules! impl_atomic_index {
+    ($($int:ty => $atomic:ty),*) => {$(
+        impl IndexType for $int {
+            type Atomic = $atomic;
+        }
+
+        // The indices are less than the `COUNT` of an enum whose `Index` is this type,
+        // so the casts don't truncate.
+        impl AtomicIndex for $atomic {
+            fn new(index: usize) -> Self {
+                <$atomic>::new(index as $int)
+            }
+            fn into_inner(self) -> usize {
+                <$atomic>::into_inner(self) as usize
+            }
+            fn load(&self, order: Ordering) -> usize {
+                <$atomic>::load(self, order) as usize
+            }
+            fn store(&self, index: usize, order: Ordering) {
+                <$atomic>::store(self, index as $int, order)
+            }
+            #[cfg(feature = "cas")]
+            fn compare_exchange(
+                &self,
+                current: usize,
+                new: usize,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<usize, usize> {
+                <$atomic>::compare_exchange(self, current as $int, new as $int, success, failure)
+                    .map(|index| index as usize)
+                    .map_err(|index| index as usize)
+            }
+            #[cfg(feature = "cas")]
+            fn fetch_update(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut f: impl FnMut(usize) -> usize,
+            ) -> usize {
+                <$atomic>::fetch_update(self, set_order, fetch_order, |index| {
+                    Some(f(index as usize) as $int)
+                })
+                .unwrap_or_else(|index| index) as usize
+            }
+        }
+    )*};
+}
+
+impl_atomic_index!(u8 => AtomicU8, u16 => AtomicU16, u32 => AtomicU32, usize => AtomicUsize);
+
+/// An enum value that can be shared between threads or with an interrupt handler,
+/// stored as the index of the variant in the smallest atomic integer that holds every index,
+/// e.g. an [`AtomicU8`] for enums with up to 256 variants.
+///
+/// ```
+/// use rotate_enum::RotateEnum;
+/// use rotate_enum_runtime::AtomicEnum;
+/// use std::sync::atomic::Ordering;
+///
+/// #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+/// #[rotate(runtime)]
+/// enum Direction {
+///     Up,
+///     Left,
+///     Down,
+///     Right,
+/// }
+///
+/// let direction = AtomicEnum::new(Direction::Down);
+/// assert_eq!(direction.fetch_next(Ordering::SeqCst), Direction::Down);
+/// assert_eq!(direction.fetch_next(Ordering::SeqCst), Direction::Right);
+/// assert_eq!(direction.load(Ordering::SeqCst), Direction::Up);
+/// assert_eq!(std::mem::size_of_val(&direction), 1);
+/// ```
+///
+/// Without the `cas` feature, only `load()` and `store()` are available, which every target
+/// with atomic integers supports.
+pub struct AtomicEnum<T: Rotate> {
+    index: <T::Index as IndexType>::Atomic,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Rotate> AtomicEnum<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            index: AtomicIndex::new(value.index()),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        Self::variant(self.index.into_inner())
+    }
+
+    pub fn load(&self, order: Ordering) -> T {
+        Self::variant(self.index.load(order))
+    }
+
+    pub fn store(&self, value: T, order: Ordering) {
+        self.index.store(value.index(), order)
+    }
+
+    /// Stores `new` if the current value is `current`, like [`AtomicUsize::compare_exchange`].
+    #[cfg(feature = "cas")]
+    pub fn compare_exchange(
+        &self,
+        current: T,
+        new: T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<T, T> {
+        self.index
+            .compare_exchange(current.index(), new.index(), success, failure)
+            .map(Self::variant)
+            .map_err(Self::variant)
+    }
+
+    /// Rotates to the next variant given by [`Rotate::next_index`], wrapping around after the last one,
+    /// and returns the previous value.
+    #[cfg(feature = "cas")]
+    pub fn fetch_next(&self, order: Ordering) -> T {
+        self.fetch_rotate(order, T::next_index)
+    }
+
+    /// Rotates to the previous variant given by [`Rotate::prev_index`], wrapping around before the first one,
+    /// and returns the previous value.
+    #[cfg(feature = "cas")]
+    pub fn fetch_prev(&self, order: Ordering) -> T {
+        self.fetch_rotate(order, T::prev_index)
+    }
+
+    #[cfg(feature = "cas")]
+    fn fetch_rotate(&self, order: Ordering, f: impl Fn(usize) -> usize) -> T {
+        // The ordering of the failed loads, which cannot be stronger than the store's.
+        let fetch_order = match order {
+            Ordering::Release => Ordering::Relaxed,
+            Ordering::AcqRel => Ordering::Acquire,
+            order => order,
+        };
+        Self::variant(self.index.fetch_update(order, fetch_order, f))
+    }
+
+    /// The index is only ever stored from a variant, so it is always valid.
+    fn variant(index: usize) -> T {
+        T::from_index(index).unwrap()
+    }
+}
+
+impl<T: Rotate> From<T> for AtomicEnum<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Rotate + fmt::Debug> fmt::Debug for AtomicEnum<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("AtomicEnum")
+            .field(&self.load(Ordering::SeqCst))
+            .finish()
+    }
+}
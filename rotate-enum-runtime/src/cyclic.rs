@@ -0,0 +1,109 @@
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// An index in `0..N` whose arithmetic wraps around at `N`, like the variants of a rotating enum.
+///
+/// The macros of rotate-enum with `#[rotate(runtime)]` convert each enum from and into
+/// `CyclicIndex<{ COUNT }>`, so numeric algorithms can work on indices and convert the result back
+/// without a fallible `from_index()`.
+///
+/// ```
+/// use rotate_enum::RotateEnum;
+/// use rotate_enum_runtime::CyclicIndex;
+///
+/// #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+/// #[rotate(runtime)]
+/// enum Direction {
+///     Up,
+///     Left,
+///     Down,
+///     Right,
+/// }
+///
+/// let index = CyclicIndex::from(Direction::Left);
+/// assert_eq!(index.get(), 1);
+/// assert_eq!(Direction::from(index + 7), Direction::Up);
+/// assert_eq!(Direction::from(index - 2), Direction::Right);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CyclicIndex<const N: usize>(usize);
+
+impl<const N: usize> CyclicIndex<N> {
+    /// Returns the index, or `None` if it is not less than `N`.
+    pub const fn new(index: usize) -> Option<Self> {
+        if index < N {
+            Some(Self(index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index wrapped around into `0..N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0, since there is no index to return.
+    pub const fn new_wrapping(index: usize) -> Self {
+        Self(index % N)
+    }
+
+    pub const fn get(self) -> usize {
+        self.0
+    }
+}
+
+impl<const N: usize> Add<usize> for CyclicIndex<N> {
+    type Output = Self;
+    fn add(self, rhs: usize) -> Self {
+        // Subtracting from N instead of adding to the index cannot overflow.
+        let rhs = rhs % N;
+        if N - rhs <= self.0 {
+            Self(self.0 - (N - rhs))
+        } else {
+            Self(self.0 + rhs)
+        }
+    }
+}
+
+impl<const N: usize> Sub<usize> for CyclicIndex<N> {
+    type Output = Self;
+    fn sub(self, rhs: usize) -> Self {
+        let rhs = rhs % N;
+        if rhs <= self.0 {
+            Self(self.0 - rhs)
+        } else {
+            Self(self.0 + (N - rhs))
+        }
+    }
+}
+
+impl<const N: usize> Add for CyclicIndex<N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        self + rhs.0
+    }
+}
+
+impl<const N: usize> Sub for CyclicIndex<N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self - rhs.0
+    }
+}
+
+impl<const N: usize> AddAssign<usize> for CyclicIndex<N> {
+    fn add_assign(&mut self, rhs: usize) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize> SubAssign<usize> for CyclicIndex<N> {
+    fn sub_assign(&mut self, rhs: usize) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize> From<CyclicIndex<N>> for usize {
+    fn from(index: CyclicIndex<N>) -> Self {
+        index.0
+    }
+}
@@ -0,0 +1,170 @@
+//! # rotate-enum-runtime
+//!
+//! Runtime support for enums annotated with the macros of [rotate-enum](https://crates.io/crates/rotate-enum).
+//!
+//! Give `#[rotate(runtime)]` to implement [`Rotate`] for an enum that gets `COUNT`, `index()`
+//! and `from_index()`, i.e. with `RotateEnum`, `ShiftEnum` or `EnumTools` with `count`.
+//! Generic code can then be written once for any of these enums.
+//!
+//! ```toml
+//! [dependencies]
+//! rotate-enum = "0.1"
+//! rotate-enum-runtime = "0.1"
+//! ```
+//!
+//! This crate is `no_std` and does not allocate, so every type in it works on bare-metal targets
+//! without `alloc`. Types that hold a value per variant use fixed-size storage instead of a `Vec`.
+//!
+//! The `cas` feature, which is enabled by default, provides the methods of [`AtomicEnum`] that need
+//! compare-and-swap instructions. Disable the default features for targets without them, like `thumbv6m-none-eabi`.
+#![no_std]
+
+mod atomic;
+mod cyclic;
+mod round_robin;
+
+pub use atomic::{AtomicEnum, AtomicIndex, IndexType};
+pub use cyclic::CyclicIndex;
+pub use round_robin::RoundRobin;
+
+/// An enum whose variants are numbered from 0 to `COUNT - 1`.
+///
+/// This is implemented by the macros of rotate-enum with `#[rotate(runtime)]`,
+/// by delegating to the generated inherent items of the same names.
+///
+/// Generic containers indexed by an enum can be written once with it, for example a counter per variant:
+///
+/// ```
+/// use rotate_enum::RotateEnum;
+/// use rotate_enum_runtime::Rotate;
+///
+/// struct Counts<T> {
+///     counts: Vec<usize>,
+///     _marker: std::marker::PhantomData<T>,
+/// }
+///
+/// impl<T: Rotate> Counts<T> {
+///     fn new() -> Self {
+///         Self { counts: vec![0; T::COUNT], _marker: std::marker::PhantomData }
+///     }
+///     fn add(&mut self, variant: &T) {
+///         self.counts[variant.index()] += 1;
+///     }
+///     fn most_common(&self) -> Option<T> {
+///         let (index, _) = self.counts.iter().enumerate().max_by_key(|(_, count)| **count)?;
+///         T::from_index(index)
+///     }
+/// }
+///
+/// #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+/// #[rotate(runtime)]
+/// enum Direction {
+///     Up,
+///     Left,
+///     Down,
+///     Right,
+/// }
+///
+/// let mut counts = Counts::new();
+/// counts.add(&Direction::Left);
+/// counts.add(&Direction::Down);
+/// counts.add(&Direction::Left);
+/// assert_eq!(counts.most_common(), Some(Direction::Left));
+/// ```
+pub trait Rotate: Sized {
+    /// The number of variants.
+    const COUNT: usize;
+
+    /// The smallest of `u8`, `u16`, `u32` and `usize` that holds every index, which sets the size of [`AtomicEnum`].
+    type Index: IndexType;
+
+    /// Returns the index of the variant.
+    fn index(&self) -> usize;
+
+    /// Returns the variant with the given index, or `None` if it is not less than `COUNT`.
+    fn from_index(index: usize) -> Option<Self>;
+
+    /// Returns the index of the variant that follows the one at `index`, which [`AtomicEnum::fetch_next`] rotates to.
+    ///
+    /// This wraps around after the last variant by default. `RotateEnum` implements it with the generated `next()`,
+    /// so the variants marked with `#[rotate(self_loop)]` rotate to themselves and are skipped by the others.
+    fn next_index(index: usize) -> usize {
+        if index + 1 < Self::COUNT {
+            index + 1
+        } else {
+            0
+        }
+    }
+
+    /// Returns the index of the variant that precedes the one at `index`, which [`AtomicEnum::fetch_prev`] rotates to.
+    ///
+    /// This wraps around before the first variant by default, and follows the generated `prev()` with `RotateEnum`.
+    fn prev_index(index: usize) -> usize {
+        if 0 < index {
+            index - 1
+        } else {
+            Self::COUNT - 1
+        }
+    }
+}
+
+/// An enum whose variants can be listed, e.g. to render a picker for any enum in a generic settings UI.
+///
+/// This is implemented along with [`Rotate`], whose `COUNT` is the length of the list.
+///
+/// ```
+/// use rotate_enum::RotateEnum;
+/// use rotate_enum_runtime::{EnumVariants, Rotate};
+///
+/// fn options<T: EnumVariants + std::fmt::Debug>() -> Vec<String> {
+///     T::variants().iter().map(|variant| format!("{:?}", variant)).collect()
+/// }
+///
+/// #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+/// #[rotate(runtime)]
+/// enum Direction {
+///     Up,
+///     Left,
+///     Down,
+///     Right,
+/// }
+///
+/// assert_eq!(options::<Direction>(), ["Up", "Left", "Down", "Right"]);
+/// assert_eq!(<Direction as Rotate>::COUNT, Direction::variants().len());
+/// ```
+pub trait EnumVariants: Rotate + 'static {
+    /// Returns all the variants in the order of their indices.
+    fn variants() -> &'static [Self];
+}
+
+/// An enum that can be iterated over from its first variant, without naming the generated iterator type.
+///
+/// This is implemented by the macros of rotate-enum that generate `iter()`, i.e. `IterEnum` or
+/// `EnumTools` with `iter`, and the iterator starts at the variant given with `#[iter(start = "Variant")]` if any.
+///
+/// ```
+/// use rotate_enum::IterEnum;
+/// use rotate_enum_runtime::IntoEnumIterator;
+///
+/// fn all<T: IntoEnumIterator>() -> Vec<T> {
+///     T::iter().collect()
+/// }
+///
+/// #[derive(IterEnum, Clone, Copy, PartialEq, Debug)]
+/// #[rotate(runtime)]
+/// enum Direction {
+///     Up,
+///     Left,
+///     Down,
+///     Right,
+/// }
+///
+/// assert_eq!(all::<Direction>(), [Direction::Up, Direction::Left, Direction::Down, Direction::Right]);
+/// ```
+pub trait IntoEnumIterator: Sized {
+    /// The generated iterator type, e.g. `DirectionIterator` for `Direction`.
+    type Iterator: Iterator<Item = Self>;
+
+    /// Returns an iterator over all the variants.
+    fn iter() -> Self::Iterator;
+}
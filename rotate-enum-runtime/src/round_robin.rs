@@ -0,0 +1,123 @@
+use crate::Rotate;
+use core::marker::PhantomData;
+
+/// A scheduler that cycles through the variants of an enum forever, in the order of their indices.
+///
+/// Variants can be removed from the rotation and added back at any time, e.g. to pause a subsystem.
+/// An added variant takes its turn at its own position, not at the end of the round.
+/// The iterator only ends if every variant is removed.
+///
+/// The turns follow the indices rather than [`Rotate::next_index`], so every variant gets one,
+/// including those marked with `#[rotate(self_loop)]` that `next()` never leaves.
+///
+/// The variants are kept in a bit set without allocating, so the enum can have at most 128 variants.
+///
+/// ```
+/// use rotate_enum::RotateEnum;
+/// use rotate_enum_runtime::RoundRobin;
+///
+/// #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+/// #[rotate(runtime)]
+/// enum Subsystem {
+///     Input,
+///     Physics,
+///     Audio,
+///     Render,
+/// }
+///
+/// let mut scheduler = RoundRobin::new();
+/// assert_eq!(scheduler.next(), Some(Subsystem::Input));
+/// scheduler.remove(&Subsystem::Physics);
+/// assert_eq!(scheduler.next(), Some(Subsystem::Audio));
+/// assert_eq!(scheduler.next(), Some(Subsystem::Render));
+/// scheduler.insert(&Subsystem::Physics);
+/// assert_eq!(scheduler.next(), Some(Subsystem::Input));
+/// assert_eq!(scheduler.next(), Some(Subsystem::Physics));
+/// ```
+#[derive(Clone)]
+pub struct RoundRobin<T> {
+    /// The index to look for the next enabled variant from.
+    cursor: usize,
+    enabled: u128,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Rotate> RoundRobin<T> {
+    /// Creates a scheduler with every variant, starting at the first one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum has more than 128 variants.
+    pub fn new() -> Self {
+        assert!(T::COUNT <= 128, "RoundRobin supports at most 128 variants");
+        Self {
+            cursor: 0,
+            enabled: u128::MAX.checked_shr(128 - T::COUNT as u32).unwrap_or(0),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a scheduler with every variant, starting at `first`.
+    pub fn starting_at(first: &T) -> Self {
+        Self {
+            cursor: first.index(),
+            ..Self::new()
+        }
+    }
+
+    /// Removes the variant from the rotation, and returns whether it was in it.
+    pub fn remove(&mut self, variant: &T) -> bool {
+        let was_enabled = self.contains(variant);
+        self.enabled &= !(1 << variant.index());
+        was_enabled
+    }
+
+    /// Adds the variant back to the rotation, and returns whether it was not in it.
+    pub fn insert(&mut self, variant: &T) -> bool {
+        let was_enabled = self.contains(variant);
+        self.enabled |= 1 << variant.index();
+        !was_enabled
+    }
+
+    /// Returns whether the variant is in the rotation.
+    pub fn contains(&self, variant: &T) -> bool {
+        self.enabled & (1 << variant.index()) != 0
+    }
+
+    /// Returns the number of variants in the rotation.
+    pub fn len(&self) -> usize {
+        self.enabled.count_ones() as usize
+    }
+
+    /// Returns whether every variant has been removed.
+    pub fn is_empty(&self) -> bool {
+        self.enabled == 0
+    }
+
+    /// Returns the variant that [`Iterator::next`] returns next, without advancing.
+    pub fn peek(&self) -> Option<T> {
+        self.next_index().and_then(T::from_index)
+    }
+
+    fn next_index(&self) -> Option<usize> {
+        (0..T::COUNT)
+            .map(|offset| (self.cursor + offset) % T::COUNT)
+            .find(|index| self.enabled & (1 << index) != 0)
+    }
+}
+
+impl<T: Rotate> Default for RoundRobin<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Rotate> Iterator for RoundRobin<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let index = self.next_index()?;
+        self.cursor = if index + 1 < T::COUNT { index + 1 } else { 0 };
+        T::from_index(index)
+    }
+}
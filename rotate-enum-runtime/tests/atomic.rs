@@ -0,0 +1,138 @@
+use rotate_enum::{EnumTools, RotateEnum, ShiftEnum};
+use rotate_enum_runtime::{AtomicEnum, Rotate};
+use std::{
+    mem,
+    sync::{atomic::Ordering, Arc},
+    thread,
+};
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+enum Light {
+    Green,
+    #[rotate(self_loop)]
+    Off,
+    Yellow,
+    Red,
+}
+
+#[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+enum Speed {
+    Slow,
+    Fast,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+#[enum_tools(count)]
+enum Single {
+    Only,
+}
+
+fn last<T: Rotate>() -> Option<T> {
+    T::COUNT.checked_sub(1).and_then(T::from_index)
+}
+
+#[test]
+fn test_rotate() {
+    assert_eq!(<Direction as Rotate>::COUNT, 4);
+    assert_eq!(Rotate::index(&Direction::Down), 2);
+    assert_eq!(last(), Some(Direction::Right));
+    assert_eq!(last(), Some(Speed::Fast));
+    assert_eq!(last(), Some(Single::Only));
+}
+
+#[test]
+fn test_load_store() {
+    let direction = AtomicEnum::new(Direction::Left);
+    assert_eq!(direction.load(Ordering::Relaxed), Direction::Left);
+    direction.store(Direction::Right, Ordering::Relaxed);
+    assert_eq!(direction.load(Ordering::Relaxed), Direction::Right);
+    assert_eq!(direction.into_inner(), Direction::Right);
+}
+
+#[test]
+fn test_fetch() {
+    let direction = AtomicEnum::from(Direction::Right);
+    assert_eq!(direction.fetch_next(Ordering::AcqRel), Direction::Right);
+    assert_eq!(direction.fetch_next(Ordering::Release), Direction::Up);
+    assert_eq!(direction.fetch_prev(Ordering::Acquire), Direction::Left);
+    assert_eq!(direction.fetch_prev(Ordering::Relaxed), Direction::Up);
+    assert_eq!(direction.load(Ordering::SeqCst), Direction::Right);
+
+    let single = AtomicEnum::new(Single::Only);
+    assert_eq!(single.fetch_next(Ordering::SeqCst), Single::Only);
+    assert_eq!(single.fetch_prev(Ordering::SeqCst), Single::Only);
+}
+
+#[test]
+fn test_fetch_self_loop() {
+    let light = AtomicEnum::new(Light::Green);
+    assert_eq!(light.fetch_next(Ordering::SeqCst), Light::Green);
+    assert_eq!(light.load(Ordering::SeqCst), Light::Yellow);
+    assert_eq!(light.fetch_prev(Ordering::SeqCst), Light::Yellow);
+    assert_eq!(light.fetch_prev(Ordering::SeqCst), Light::Green);
+    assert_eq!(light.load(Ordering::SeqCst), Light::Red);
+    assert_eq!(light.fetch_next(Ordering::SeqCst), Light::Red);
+    assert_eq!(light.load(Ordering::SeqCst), Light::Green);
+
+    let off = AtomicEnum::new(Light::Off);
+    assert_eq!(off.fetch_next(Ordering::SeqCst), Light::Off);
+    assert_eq!(off.fetch_prev(Ordering::SeqCst), Light::Off);
+    assert_eq!(off.load(Ordering::SeqCst), Light::Off);
+
+    assert_eq!(<Light as Rotate>::next_index(1), 1);
+    assert_eq!(<Speed as Rotate>::next_index(1), 0);
+}
+
+#[test]
+fn test_compare_exchange() {
+    let speed = AtomicEnum::new(Speed::Slow);
+    let (success, failure) = (Ordering::SeqCst, Ordering::SeqCst);
+    assert_eq!(
+        speed.compare_exchange(Speed::Fast, Speed::Slow, success, failure),
+        Err(Speed::Slow)
+    );
+    assert_eq!(
+        speed.compare_exchange(Speed::Slow, Speed::Fast, success, failure),
+        Ok(Speed::Slow)
+    );
+    assert_eq!(format!("{:?}", speed), "AtomicEnum(Fast)");
+}
+
+#[test]
+fn test_threads() {
+    let direction = Arc::new(AtomicEnum::new(Direction::Up));
+    let threads = (0..4)
+        .map(|_| {
+            let direction = Arc::clone(&direction);
+            thread::spawn(move || {
+                for _ in 0..1001 {
+                    direction.fetch_next(Ordering::Relaxed);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    assert_eq!(direction.load(Ordering::SeqCst), Direction::Up);
+}
+
+#[test]
+fn test_size() {
+    let index: <Direction as Rotate>::Index = 3u8;
+    assert_eq!(Direction::from_index(index.into()), Some(Direction::Right));
+    assert_eq!(mem::size_of::<AtomicEnum<Direction>>(), 1);
+    assert_eq!(mem::size_of::<AtomicEnum<Single>>(), 1);
+}
@@ -0,0 +1,76 @@
+use rotate_enum::{EnumTools, RotateEnum, ShiftEnum};
+use rotate_enum_runtime::CyclicIndex;
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+enum Speed {
+    Slow,
+    Fast,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+#[enum_tools(count)]
+enum Single {
+    Only,
+}
+
+#[test]
+fn test_new() {
+    assert_eq!(CyclicIndex::<4>::new(3).map(CyclicIndex::get), Some(3));
+    assert_eq!(CyclicIndex::<4>::new(4), None);
+    assert_eq!(CyclicIndex::<0>::new(0), None);
+    assert_eq!(CyclicIndex::<4>::new_wrapping(9).get(), 1);
+    assert_eq!(usize::from(CyclicIndex::<4>::new_wrapping(2)), 2);
+}
+
+#[test]
+fn test_arithmetic() {
+    let index = CyclicIndex::<4>::new_wrapping(3);
+    assert_eq!((index + 1).get(), 0);
+    assert_eq!((index + 6).get(), 1);
+    assert_eq!((index - 3).get(), 0);
+    assert_eq!((index - 5).get(), 2);
+    assert_eq!((index + usize::MAX).get(), (3 + usize::MAX % 4) % 4);
+    assert_eq!((index - usize::MAX).get(), (3 + 4 - usize::MAX % 4) % 4);
+    assert_eq!((index + index).get(), 2);
+    assert_eq!((index - index).get(), 0);
+
+    let mut index = index;
+    index += 2;
+    assert_eq!(index.get(), 1);
+    index -= 2;
+    assert_eq!(index.get(), 3);
+
+    let large = CyclicIndex::<{ usize::MAX }>::new_wrapping(usize::MAX - 1);
+    assert_eq!((large + (usize::MAX - 1)).get(), usize::MAX - 2);
+    assert_eq!((large - (usize::MAX - 1)).get(), 0);
+}
+
+#[test]
+fn test_conversions() {
+    for i in 0..Direction::COUNT {
+        let direction = Direction::from_index(i).unwrap();
+        let index = CyclicIndex::from(direction);
+        assert_eq!(index.get(), i);
+        assert_eq!(Direction::from(index), direction);
+        assert_eq!(Direction::from(index + 1), direction.next());
+        assert_eq!(Direction::from(index - 1), direction.prev());
+    }
+
+    let fast: CyclicIndex<2> = Speed::Fast.into();
+    assert_eq!(Speed::from(fast + 1), Speed::Slow);
+    assert_eq!(
+        Single::from(CyclicIndex::from(Single::Only) + 5),
+        Single::Only
+    );
+}
@@ -0,0 +1,45 @@
+use rotate_enum::{EnumTools, IterEnum};
+use rotate_enum_runtime::IntoEnumIterator;
+
+#[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+#[iter(start = "Medium")]
+enum Size {
+    Small,
+    Medium,
+    Large,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+#[enum_tools(rotate, iter)]
+enum Empty {}
+
+fn all<T: IntoEnumIterator>() -> Vec<T> {
+    T::iter().collect()
+}
+
+#[test]
+fn test_into_enum_iterator() {
+    assert_eq!(
+        all::<Direction>(),
+        [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right
+        ]
+    );
+    assert_eq!(all::<Size>(), [Size::Medium, Size::Large, Size::Small]);
+    assert!(all::<Empty>().is_empty());
+    assert_eq!(<Direction as IntoEnumIterator>::iter().len(), 4);
+}
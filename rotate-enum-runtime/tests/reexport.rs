@@ -0,0 +1,40 @@
+use rotate_enum::{IterEnum, RotateEnum};
+
+/// Stands in for a framework that re-exports rotate-enum-runtime.
+mod framework {
+    pub mod macros {
+        pub use rotate_enum_runtime as runtime;
+    }
+}
+
+use framework::macros::runtime::{CyclicIndex, EnumVariants, IntoEnumIterator, Rotate};
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime, crate = "framework::macros::runtime")]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime, crate = "crate::framework::macros::runtime")]
+enum Speed {
+    Slow,
+    Fast,
+}
+
+#[test]
+fn test_reexported_path() {
+    assert_eq!(<Direction as Rotate>::COUNT, 4);
+    assert_eq!(Direction::variants().len(), 4);
+    assert_eq!(
+        Direction::from(CyclicIndex::<4>::new(3).unwrap()),
+        Direction::Right
+    );
+    assert_eq!(
+        <Speed as IntoEnumIterator>::iter().collect::<Vec<_>>(),
+        vec![Speed::Slow, Speed::Fast]
+    );
+}
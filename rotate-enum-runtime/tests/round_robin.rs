@@ -0,0 +1,89 @@
+use rotate_enum::{EnumTools, RotateEnum};
+use rotate_enum_runtime::RoundRobin;
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+enum Light {
+    Green,
+    #[rotate(self_loop)]
+    Off,
+    Yellow,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+#[enum_tools(count)]
+enum Empty {}
+
+#[test]
+fn test_cycle() {
+    let scheduler = RoundRobin::<Direction>::new();
+    assert_eq!(scheduler.len(), 4);
+    assert_eq!(
+        scheduler.take(6).collect::<Vec<_>>(),
+        [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+            Direction::Up,
+            Direction::Left
+        ]
+    );
+
+    let mut scheduler = RoundRobin::starting_at(&Direction::Right);
+    assert_eq!(scheduler.peek(), Some(Direction::Right));
+    assert_eq!(scheduler.next(), Some(Direction::Right));
+    assert_eq!(scheduler.next(), Some(Direction::Up));
+}
+
+#[test]
+fn test_remove_insert() {
+    let mut scheduler = RoundRobin::new();
+    assert!(scheduler.remove(&Direction::Left));
+    assert!(!scheduler.remove(&Direction::Left));
+    assert!(scheduler.remove(&Direction::Up));
+    assert!(!scheduler.contains(&Direction::Up));
+    assert_eq!(scheduler.len(), 2);
+    assert_eq!(scheduler.next(), Some(Direction::Down));
+    assert_eq!(scheduler.next(), Some(Direction::Right));
+    assert_eq!(scheduler.next(), Some(Direction::Down));
+
+    assert!(scheduler.insert(&Direction::Up));
+    assert!(!scheduler.insert(&Direction::Up));
+    assert_eq!(scheduler.next(), Some(Direction::Right));
+    assert_eq!(scheduler.next(), Some(Direction::Up));
+
+    scheduler.remove(&Direction::Up);
+    scheduler.remove(&Direction::Down);
+    scheduler.remove(&Direction::Right);
+    assert!(scheduler.is_empty());
+    assert_eq!(scheduler.peek(), None);
+    assert_eq!(scheduler.next(), None);
+}
+
+#[test]
+fn test_empty() {
+    let mut scheduler = RoundRobin::<Empty>::default();
+    assert!(scheduler.is_empty());
+    assert_eq!(scheduler.next(), None);
+}
+
+#[test]
+fn test_self_loop() {
+    assert_eq!(Light::Off.next(), Light::Off);
+    let scheduler = RoundRobin::starting_at(&Light::Off);
+    assert_eq!(
+        scheduler.take(4).collect::<Vec<_>>(),
+        [Light::Off, Light::Yellow, Light::Green, Light::Off]
+    );
+}
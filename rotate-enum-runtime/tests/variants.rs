@@ -0,0 +1,48 @@
+use rotate_enum::{EnumTools, RotateEnum, ShiftEnum};
+use rotate_enum_runtime::{EnumVariants, Rotate};
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(ShiftEnum, PartialEq, Debug)]
+#[rotate(runtime)]
+enum Speed {
+    Slow,
+    Fast,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[rotate(runtime)]
+#[enum_tools(count)]
+enum Empty {}
+
+fn check_indices<T: EnumVariants + PartialEq + std::fmt::Debug>() {
+    assert_eq!(T::variants().len(), <T as Rotate>::COUNT);
+    for (i, variant) in T::variants().iter().enumerate() {
+        assert_eq!(variant.index(), i);
+    }
+}
+
+#[test]
+fn test_variants() {
+    assert_eq!(
+        Direction::variants(),
+        [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right
+        ]
+    );
+    assert_eq!(Speed::variants(), [Speed::Slow, Speed::Fast]);
+    assert!(Empty::variants().is_empty());
+    check_indices::<Direction>();
+    check_indices::<Speed>();
+    check_indices::<Empty>();
+}
@@ -0,0 +1,59 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the conversions from and into `bool` and `Not` for an enum with exactly two variants.
+///
+/// The second variant is `true` unless another one is given with `#[rotate(true_variant = "Variant")]`.
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<Option<TokenStream>> {
+    let name = info.name;
+    let true_index = match &info.options.true_variant {
+        Some(lit) if info.variants.len() != 2 => {
+            return Err(syn::Error::new_spanned(
+                lit,
+                "true_variant needs an enum with exactly two variants",
+            ))
+        }
+        Some(lit) => info.find_variant(lit)?,
+        None if info.variants.len() != 2 => return Ok(None),
+        None => 1,
+    };
+    let true_variant = info.variants[true_index];
+    let false_variant = info.variants[1 - true_index];
+    let patterns = info.variant_patterns(&quote! { #name });
+    let (true_pattern, false_pattern) = (&patterns[true_index], &patterns[1 - true_index]);
+
+    Ok(Some(quote! {
+        #[allow(deprecated)]
+        impl ::core::convert::From<bool> for #name {
+            fn from(value: bool) -> Self {
+                if value {
+                    Self::#true_variant
+                } else {
+                    Self::#false_variant
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::ops::Not for #name {
+            type Output = Self;
+            fn not(self) -> Self {
+                match self {
+                    #true_pattern => Self::#false_variant,
+                    #false_pattern => Self::#true_variant,
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::convert::From<#name> for bool {
+            fn from(value: #name) -> Self {
+                match value {
+                    #true_pattern => true,
+                    #false_pattern => false,
+                }
+            }
+        }
+    }))
+}
@@ -0,0 +1,31 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<TokenStream> {
+    let name = info.name;
+
+    Ok(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            pub fn bounce(self, forward: bool) -> (Self, bool) {
+                if Self::COUNT <= 1 {
+                    return (self, forward);
+                }
+                let index = self.index();
+                // Turn around at either end instead of stepping past it.
+                let forward = if forward { index + 1 < Self::COUNT } else { index == 0 };
+                let index = if forward { index + 1 } else { index - 1 };
+                (Self::from_index(index).unwrap(), forward)
+            }
+            pub fn bounce_iter(self, forward: bool) -> impl ::core::iter::Iterator<Item = Self> {
+                let mut state = (self.index(), forward);
+                ::core::iter::from_fn(move || {
+                    let (variant, forward) = Self::from_index(state.0).unwrap().bounce(state.1);
+                    state = (variant.index(), forward);
+                    ::core::option::Option::Some(variant)
+                })
+            }
+        }
+    })
+}
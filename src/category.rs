@@ -0,0 +1,53 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+
+/// Generates `category()` if the variants have `#[category(...)]` attributes.
+///
+/// The return type is the path of the categories without their last segment, e.g. `Axis` for
+/// `Axis::Vertical`, so all of them need to name variants of the same enum.
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<Option<TokenStream>> {
+    let categories = match info.variant_args::<Path>("category")? {
+        Some(categories) => categories,
+        None => return Ok(None),
+    };
+    let category_ty = category_type(&categories[0])?;
+    let expected = quote!(#category_ty).to_string();
+    for category in &categories[1..] {
+        let ty = category_type(category)?;
+        if quote!(#ty).to_string() != expected {
+            return Err(syn::Error::new_spanned(
+                category,
+                "all categories must be variants of the same enum",
+            ));
+        }
+    }
+    let name = info.name;
+    let patterns = info.variant_patterns(&quote! { Self });
+
+    Ok(Some(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            pub fn category(&self) -> #category_ty {
+                match *self {
+                    #(#patterns => #categories, )*
+                }
+            }
+        }
+    }))
+}
+
+fn category_type(category: &Path) -> syn::Result<Path> {
+    let count = category.segments.len();
+    if count < 2 {
+        return Err(syn::Error::new_spanned(
+            category,
+            "expected a path to a variant, e.g. `Axis::Vertical`",
+        ));
+    }
+    Ok(Path {
+        leading_colon: category.leading_colon,
+        segments: category.segments.iter().take(count - 1).cloned().collect(),
+    })
+}
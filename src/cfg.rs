@@ -0,0 +1,61 @@
+use crate::model::EnumInfo;
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use quote::quote;
+
+/// Adds the attributes given with `#[rotate(cfg(...))]` and `#[rotate(cfg_attr(...))]` to each
+/// generated item, so that the user can gate or decorate the whole generated API.
+///
+/// The generated code is split into items at the token level instead of being parsed with syn,
+/// which relies on every generated item ending with either a `;` or a brace-delimited body.
+pub(crate) fn apply(info: &EnumInfo, expanded: TokenStream) -> TokenStream {
+    let item_attrs = &info.options.item_attrs;
+    if item_attrs.is_empty() {
+        return expanded;
+    }
+    let attrs = quote! { #(#[#item_attrs])* };
+    let mut output = TokenStream::new();
+    let mut item = Vec::new();
+    let mut tokens = expanded.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        let ends_item = match &token {
+            TokenTree::Punct(punct) => punct.as_char() == ';',
+            TokenTree::Group(group) => {
+                group.delimiter() == Delimiter::Brace && starts_item(tokens.peek())
+            }
+            _ => false,
+        };
+        item.push(token);
+        if ends_item {
+            output.extend(attrs.clone());
+            output.extend(item.drain(..));
+        }
+    }
+    output.extend(item);
+    output
+}
+
+/// Whether the token after a brace-delimited group begins the next item, which tells the body
+/// of an item apart from braces in its header, such as a const generic argument.
+fn starts_item(next: Option<&TokenTree>) -> bool {
+    match next {
+        None => true,
+        Some(TokenTree::Punct(punct)) => punct.as_char() == '#',
+        Some(TokenTree::Ident(ident)) => [
+            "const",
+            "enum",
+            "fn",
+            "impl",
+            "macro_rules",
+            "mod",
+            "pub",
+            "static",
+            "struct",
+            "trait",
+            "type",
+            "unsafe",
+            "use",
+        ]
+        .contains(&ident.to_string().as_str()),
+        _ => false,
+    }
+}
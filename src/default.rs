@@ -0,0 +1,34 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `impl Default` if `#[rotate(default)]` or `#[rotate(default = "Variant")]` is given.
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<Option<TokenStream>> {
+    let lit = if let Some(lit) = &info.options.default {
+        lit
+    } else {
+        return Ok(None);
+    };
+
+    let name = info.name;
+    let index = if let Some(lit) = lit {
+        info.find_variant(lit)?
+    } else if info.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            name,
+            "#[rotate(default)] needs at least one variant",
+        ));
+    } else {
+        0
+    };
+    let variant = info.variants[index];
+
+    Ok(Some(quote! {
+        #[allow(deprecated)]
+        impl ::core::default::Default for #name {
+            fn default() -> Self {
+                Self::#variant
+            }
+        }
+    }))
+}
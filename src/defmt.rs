@@ -0,0 +1,29 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Implements `defmt::Format` with the variant names if the enum has `#[rotate(defmt)]`.
+pub(crate) fn generate(info: &EnumInfo) -> Option<TokenStream> {
+    if !info.options.defmt {
+        return None;
+    }
+
+    let name = info.name;
+    let skipped = info.skipped_idents();
+    let skipped_names = info.skipped_names();
+    let variants = &info.variants;
+    // The names are format strings of their own, which defmt interns instead of storing them on the device.
+    let names = info.variant_names();
+
+    Some(quote! {
+        #[allow(deprecated)]
+        impl ::defmt::Format for #name {
+            fn format(&self, f: ::defmt::Formatter) {
+                match *self {
+                    #(Self::#variants => ::defmt::write!(f, #names), )*
+                    #(Self::#skipped { .. } => ::defmt::write!(f, #skipped_names), )*
+                }
+            }
+        }
+    })
+}
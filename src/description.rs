@@ -0,0 +1,55 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Lit, Meta, MetaNameValue};
+
+/// Generates `description()` from the doc comments of the variants if `#[rotate(description)]` is given.
+pub(crate) fn generate(info: &EnumInfo) -> Option<TokenStream> {
+    if !info.options.description {
+        return None;
+    }
+
+    let name = info.name;
+    let variants = &info.variants;
+    let descriptions = info.variant_attrs.iter().map(|attrs| description(attrs));
+    // The variants skipped by `#[rotate(skip_data)]` are described by their own doc comments.
+    let skipped = info.skipped_idents();
+    let skipped_descriptions = info
+        .skipped
+        .iter()
+        .map(|skipped| description(skipped.attrs));
+
+    Some(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            pub fn description(&self) -> &'static str {
+                match *self {
+                    #(Self::#variants => #descriptions, )*
+                    #(Self::#skipped { .. } => #skipped_descriptions, )*
+                }
+            }
+        }
+    })
+}
+
+/// Joins the lines of the doc comment of a variant.
+fn description(attrs: &[Attribute]) -> String {
+    // Each line of a doc comment is a `#[doc = "..."]` attribute, which starts with the space after `///`.
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(lit), ..
+            })) => Some(lit.value()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    lines
+        .iter()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
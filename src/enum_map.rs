@@ -0,0 +1,32 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Implements enum-map's `Enum` and `EnumArray` if the enum has `#[rotate(enum_map)]`,
+/// so that the enum can be the key of an `EnumMap` without deriving `Enum` too.
+pub(crate) fn generate(info: &EnumInfo) -> Option<TokenStream> {
+    if !info.options.enum_map {
+        return None;
+    }
+    let name = info.name;
+    let count = info.variants.len();
+    let message = format!("index out of range for {}", name);
+
+    Some(quote! {
+        #[allow(deprecated)]
+        impl ::enum_map::Enum for #name {
+            const LENGTH: usize = #count;
+            fn from_usize(value: usize) -> Self {
+                Self::from_index(value).expect(#message)
+            }
+            fn into_usize(self) -> usize {
+                self.index()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<V> ::enum_map::EnumArray<V> for #name {
+            type Array = [V; #count];
+        }
+    })
+}
@@ -0,0 +1,118 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, Fields, Index, Type};
+
+/// Generates an iterator for `#[iter(flatten)]`, which yields every unit variant once and every
+/// single-field tuple variant once per variant of the field's enum, which must derive `IterEnum` itself.
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<TokenStream> {
+    let name = info.name;
+    let vis = info.type_vis();
+    let iterator_name = info.derived_ident("Iterator");
+
+    let mut sub_iterators = vec![];
+    let mut arms = vec![];
+    let mut lengths = vec![];
+    for ((index, variant), fields) in info.variants.iter().enumerate().zip(&info.fields) {
+        match fields {
+            Fields::Unit => {
+                arms.push(quote! {
+                    #index => {
+                        self.0 += 1;
+                        return ::core::option::Option::Some(#name::#variant);
+                    }
+                });
+                lengths.push(quote! { 1 });
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let sub_iterator = sub_iterator_type(&fields.unnamed[0].ty)?;
+                let field = Index::from(sub_iterators.len() + 1);
+                arms.push(quote! {
+                    #index => {
+                        if let ::core::option::Option::Some(item) = ::core::iter::Iterator::next(&mut self.#field) {
+                            return ::core::option::Option::Some(#name::#variant(item));
+                        }
+                        self.0 += 1;
+                    }
+                });
+                lengths.push(quote! { ::core::iter::ExactSizeIterator::len(&self.#field) });
+                sub_iterators.push(sub_iterator);
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    fields,
+                    "#[iter(flatten)] only supports unit variants and variants with a single unnamed field",
+                ))
+            }
+        }
+    }
+    let indices = info.indices();
+
+    let items = quote! {
+        #vis struct #iterator_name(usize, #(#sub_iterators, )*);
+
+        #[allow(deprecated)]
+        impl #iterator_name {
+            pub fn new() -> Self {
+                Self(0, #(#sub_iterators::new(), )*)
+            }
+            pub fn remaining(&self) -> usize {
+                let mut remaining = 0;
+                #(if self.0 <= #indices {
+                    remaining += #lengths;
+                })*
+                remaining
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::default::Default for #iterator_name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::iter::Iterator for #iterator_name {
+            type Item = #name;
+            fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                loop {
+                    match self.0 {
+                        #(#arms)*
+                        _ => return ::core::option::Option::None,
+                    }
+                }
+            }
+            fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+                (self.remaining(), ::core::option::Option::Some(self.remaining()))
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::iter::ExactSizeIterator for #iterator_name {}
+    };
+
+    Ok(info.namespace(&iterator_name, items))
+}
+
+/// Finds the iterator `IterEnum` generated for the field type, e.g. `items::FileIterator` for `items::File`.
+fn sub_iterator_type(ty: &Type) -> syn::Result<syn::Path> {
+    if let Type::Path(ty) = ty {
+        if ty.qself.is_none() {
+            let mut path = ty.path.clone();
+            if let Some(last) = path.segments.last_mut() {
+                if last.arguments.is_empty() {
+                    last.ident = syn::Ident::new(
+                        &format!("{}Iterator", last.ident.unraw()),
+                        last.ident.span(),
+                    );
+                    return Ok(path);
+                }
+            }
+        }
+    }
+    Err(syn::Error::new_spanned(
+        ty,
+        "#[iter(flatten)] expects the field to be an enum deriving IterEnum",
+    ))
+}
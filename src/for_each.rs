@@ -0,0 +1,95 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    Ident, Path, Token,
+};
+
+/// The name of a `macro_rules!` macro that a function-like macro forwards to, such as
+/// `__rotate_enum_for_each_variant_Direction` for `for_each_variant!(Direction, ...)`.
+/// A procedural macro cannot look up the variants of another item, so the macros that
+/// see the enum define this helper for it.
+pub(crate) fn helper_ident(kind: &str, name: &Ident) -> Ident {
+    Ident::new(
+        &format!("__rotate_enum_{}_{}", kind, name.unraw()),
+        name.span(),
+    )
+}
+
+/// Defines a helper macro next to the enum.
+///
+/// The macro is also imported into the module of the enum, so that it can be named by a path
+/// like the enum itself, e.g. `crate::dirs::__rotate_enum_for_each_variant_Direction!`.
+/// Only macros exported from the crate root can be reached from other crates, which would make
+/// helpers of enums with the same name collide, so the path has to be within the crate.
+pub(crate) fn define_helper(helper: &Ident, rules: TokenStream) -> TokenStream {
+    quote! {
+        #[allow(unused_macros)]
+        macro_rules! #helper {
+            #rules
+        }
+        #[allow(unused_imports)]
+        pub(crate) use #helper;
+    }
+}
+
+/// Replaces the enum name at the end of a path with the helper macro of the enum,
+/// so that the helper is found wherever the path leads to the enum.
+pub(crate) fn helper_path(enum_path: &Path, kind: &str) -> Path {
+    let mut path = enum_path.clone();
+    if let Some(last) = path.segments.last_mut() {
+        last.ident = helper_ident(kind, &last.ident);
+    }
+    path
+}
+
+/// Generates the helper macro that repeats a body once for each variant.
+/// The variant is bound to a `const` so that the body can use it in constant expressions.
+/// The path of the enum is passed to the helper, since the name of the enum may not be in
+/// scope where the helper is called.
+pub(crate) fn generate(info: &EnumInfo) -> TokenStream {
+    let variants = &info.variants;
+    let helper = helper_ident("for_each_variant", info.name);
+
+    define_helper(
+        &helper,
+        quote! {
+            ([$($path:tt)*] |$variant:ident| $($body:tt)*) => {{
+                #({
+                    #[allow(non_upper_case_globals)]
+                    #[allow(deprecated)]
+                    const $variant: $($path)* = $($path)*::#variants;
+                    $($body)*
+                })*
+            }};
+        },
+    )
+}
+
+/// The arguments of `for_each_variant!(Enum, |variant| body)`.
+pub(crate) struct ForEachVariant {
+    path: Path,
+    body: TokenStream,
+}
+
+impl Parse for ForEachVariant {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        input.parse::<Token![,]>()?;
+        Ok(Self {
+            path,
+            body: input.parse()?,
+        })
+    }
+}
+
+/// Expands `for_each_variant!` into a call to the helper macro of the enum.
+pub(crate) fn expand(input: ForEachVariant) -> TokenStream {
+    let ForEachVariant { path, body } = input;
+    let helper = helper_path(&path, "for_each_variant");
+    quote! {
+        #helper!([#path] #body)
+    }
+}
@@ -0,0 +1,47 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the group operations of the rotations if `#[rotate(group)]` is given, treating the variants
+/// as the integers modulo `COUNT`: `Add` composes two rotations, `IDENTITY` is the first variant
+/// and `inverse()` undoes a rotation.
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<Option<TokenStream>> {
+    if !info.options.group {
+        return Ok(None);
+    }
+    // A self loop is a rotation that never moves, so it cannot stand for an integer modulo `COUNT`.
+    let self_loop = info
+        .variants
+        .iter()
+        .zip(&info.self_loops)
+        .find(|(_, self_loop)| **self_loop);
+    if let Some((variant, _)) = self_loop {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "group cannot be combined with self_loop variants",
+        ));
+    }
+    let name = info.name;
+    let first = match info.variants.first() {
+        Some(first) => first,
+        None => return Ok(None),
+    };
+
+    Ok(Some(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            pub const IDENTITY: Self = Self::#first;
+            pub fn inverse(self) -> Self {
+                Self::from_index((Self::COUNT - self.index()).rem_euclid(Self::COUNT)).unwrap()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::ops::Add for #name {
+            type Output = Self;
+            fn add(self, other: Self) -> Self {
+                self.wrapping_add(other.index())
+            }
+        }
+    }))
+}
@@ -0,0 +1,50 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `COUNT`, `index()` and `from_index()`. Every other generated method
+/// is written in terms of these, so they are the only places that need an arm per variant.
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<TokenStream> {
+    let name = info.name;
+    let patterns = info.variant_patterns(&quote! { Self });
+    let variants = &info.variants;
+    let count = variants.len();
+    let indices = info.indices();
+
+    Ok(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            pub const COUNT: usize = #count;
+            pub fn index(&self) -> usize {
+                match *self {
+                    #(#patterns => #indices, )*
+                }
+            }
+            pub fn from_index(index: usize) -> ::core::option::Option<Self> {
+                match index {
+                    #(#indices => ::core::option::Option::Some(Self::#variants), )*
+                    _ => ::core::option::Option::None,
+                }
+            }
+            pub fn from_index_wrapping(index: usize) -> Self {
+                Self::from_index(index.rem_euclid(Self::COUNT)).unwrap()
+            }
+            pub fn from_index_clamped(index: usize) -> Self {
+                Self::from_index(::core::cmp::Ord::min(index, Self::COUNT.saturating_sub(1))).unwrap()
+            }
+            pub fn count_occurrences<I: ::core::iter::IntoIterator<Item = Self>>(iter: I) -> [usize; #count] {
+                let mut counts = [0; #count];
+                for variant in iter {
+                    counts[variant.index()] += 1;
+                }
+                counts
+            }
+            pub fn for_each(mut f: impl ::core::ops::FnMut(Self)) {
+                #(f(Self::#variants);)*
+            }
+            pub fn map_variants<T>(mut f: impl ::core::ops::FnMut(Self) -> T) -> [T; #count] {
+                [#(f(Self::#variants), )*]
+            }
+        }
+    })
+}
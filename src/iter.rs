@@ -0,0 +1,133 @@
+use crate::{
+    flatten,
+    model::{options, EnumInfo},
+    runtime,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Lit, Meta, MetaNameValue};
+
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<TokenStream> {
+    let name = info.name;
+    let patterns = info.variant_patterns(&quote! { #name });
+    let vis = info.type_vis();
+    let variants = &info.variants;
+    let indices = info.indices();
+    let count = variants.len();
+
+    let mut start = None;
+    let mut flatten = None;
+    for option in options(info.attrs, "iter")? {
+        match &option {
+            Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(lit),
+                ..
+            }) if path.is_ident("start") => start = Some(info.find_variant(lit)?),
+            Meta::Path(path) if path.is_ident("flatten") => flatten = Some(path.clone()),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    option,
+                    "unknown iter option, expected start = \"Variant\" or flatten",
+                ))
+            }
+        }
+    }
+    if let Some(flatten) = flatten {
+        if start.is_some() {
+            return Err(syn::Error::new_spanned(
+                flatten,
+                "flatten cannot be combined with start",
+            ));
+        }
+        if info.options.skip_data {
+            return Err(syn::Error::new_spanned(
+                flatten,
+                "flatten cannot be combined with skip_data",
+            ));
+        }
+        return flatten::generate(info);
+    }
+    let start = start.unwrap_or(0);
+
+    let iterator_name = info.derived_ident("Iterator");
+    let runtime = runtime::generate_iter(info, &iterator_name);
+
+    let items = quote! {
+
+        #vis struct #iterator_name(usize, usize);
+
+        #[allow(deprecated)]
+        impl #iterator_name {
+            pub fn new() -> Self {
+                Self(#start, #count)
+            }
+            pub fn remaining(&self) -> usize {
+                self.1
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::default::Default for #iterator_name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::iter::Iterator for #iterator_name {
+            type Item = #name;
+            fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                if self.1 == 0 {
+                    return ::core::option::Option::None;
+                }
+                let ret = match self.0 {
+                    #(#indices => ::core::option::Option::Some(#name::#variants), )*
+                    _ => ::core::option::Option::None,
+                };
+                self.0 = if self.0 + 1 == #count { 0 } else { self.0 + 1 };
+                self.1 -= 1;
+                ret
+            }
+            fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+                (self.remaining(), ::core::option::Option::Some(self.remaining()))
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::iter::ExactSizeIterator for #iterator_name {}
+
+        #[allow(deprecated)]
+        impl #name {
+            pub fn iter(&self) -> #iterator_name {
+                match *self {
+                    #(#patterns => #iterator_name(#indices, #count - #indices), )*
+                }
+            }
+            pub fn iter_refs(&self) -> ::core::slice::Iter<'static, #name> {
+                static VARIANTS: [#name; #count] = [#(#name::#variants, )*];
+                VARIANTS[self.iter().0..].iter()
+            }
+            pub fn iter_indexed(&self) -> impl ::core::iter::Iterator<Item = (usize, #name)> {
+                let mut iter = self.iter();
+                ::core::iter::from_fn(move || {
+                    let index = iter.0;
+                    ::core::iter::Iterator::next(&mut iter).map(|variant| (index, variant))
+                })
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<'a> ::core::iter::IntoIterator for &'a #name {
+            type Item = #name;
+            type IntoIter = #iterator_name;
+            fn into_iter(self) -> #iterator_name {
+                self.iter()
+            }
+        }
+
+        #runtime
+    };
+
+    Ok(info.namespace(&iterator_name, items))
+}
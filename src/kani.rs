@@ -0,0 +1,82 @@
+use crate::{model::EnumInfo, self_test, shift, tools::Tools};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `#[cfg(kani)]` proof harnesses for the Kani model checker if `#[rotate(kani)]` is given.
+/// They check the same invariants as `#[rotate(self_test)]`, but for every index and step count
+/// instead of the ones a test happens to try.
+pub(crate) fn generate(info: &EnumInfo, tools: &Tools) -> Option<TokenStream> {
+    if !info.options.kani || info.carries_data() {
+        return None;
+    }
+
+    let name = info.name;
+    let module = self_test::module_ident(info, tools, "kani");
+
+    let mut proofs = vec![];
+    if tools.count {
+        proofs.push(quote! {
+            #[::kani::proof]
+            fn index() {
+                let index: usize = ::kani::any();
+                match #name::from_index(index) {
+                    Some(variant) => assert!(index < #name::COUNT && variant.index() == index),
+                    None => assert!(#name::COUNT <= index),
+                }
+            }
+        });
+    }
+    if tools.rotate {
+        proofs.push(quote! {
+            #[::kani::proof]
+            fn rotate() {
+                let index = any_index();
+                assert!(variant(index).next().prev().index() == index);
+                assert!(variant(index).prev().next().index() == index);
+                let n: usize = ::kani::any();
+                assert!(variant(index).wrapping_add(n).wrapping_sub(n).index() == index);
+            }
+        });
+    }
+    if tools.shift {
+        let next = shift::next_fn(info);
+        proofs.push(quote! {
+            #[::kani::proof]
+            fn shift() {
+                let index = any_index();
+                if index + 1 < #name::COUNT {
+                    assert!(variant(index).next().and_then(#name::prev).map(|v| v.index()) == Some(index));
+                }
+                if 0 < index {
+                    assert!(variant(index).prev().and_then(#next).map(|v| v.index()) == Some(index));
+                }
+            }
+        });
+    }
+
+    Some(quote! {
+        #[cfg(kani)]
+        #[allow(non_snake_case, deprecated, dead_code)]
+        mod #module {
+            #[allow(unused_imports)]
+            use ::core::{
+                assert,
+                iter::Iterator,
+                option::Option::{None, Some},
+            };
+            use super::#name;
+
+            fn any_index() -> usize {
+                let index: usize = ::kani::any();
+                ::kani::assume(index < #name::COUNT);
+                index
+            }
+
+            fn variant(index: usize) -> #name {
+                #name::from_index(index).unwrap()
+            }
+
+            #(#proofs)*
+        }
+    })
+}
@@ -0,0 +1,45 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitChar;
+
+/// Generates `to_char()` and `TryFrom<char>` if the variants have `#[key(...)]` attributes.
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<Option<TokenStream>> {
+    let keys = match info.variant_args::<LitChar>("key")? {
+        Some(keys) => keys,
+        None => return Ok(None),
+    };
+    for (i, key) in keys.iter().enumerate() {
+        if keys[..i].iter().any(|other| other.value() == key.value()) {
+            return Err(syn::Error::new_spanned(
+                key,
+                "this key is already used by another variant",
+            ));
+        }
+    }
+    let name = info.name;
+    let patterns = info.variant_patterns(&quote! { Self });
+    let variants = &info.variants;
+
+    Ok(Some(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            pub fn to_char(&self) -> char {
+                match *self {
+                    #(#patterns => #keys, )*
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::convert::TryFrom<char> for #name {
+            type Error = char;
+            fn try_from(key: char) -> ::core::result::Result<Self, char> {
+                match key {
+                    #(#keys => ::core::result::Result::Ok(Self::#variants), )*
+                    _ => ::core::result::Result::Err(key),
+                }
+            }
+        }
+    }))
+}
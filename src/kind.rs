@@ -0,0 +1,90 @@
+use crate::{
+    model::EnumInfo,
+    tools::{strip_helper_attrs, Tools},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Ident};
+
+/// Generates the fieldless enum declared with `#[kind(Name)]`, which has the same variants
+/// without their data and the same tools as the enum, and `kind()` that returns the variant of it.
+///
+/// The options in `#[rotate(...)]`, `#[iter(...)]` and `#[shift(...)]` on the enum and in `#[rotate(...)]`
+/// and `#[name(...)]` on the variants apply to the kind too, so that it rotates and iterates like
+/// the enum would if it had no data.
+pub(crate) fn generate(
+    info: &EnumInfo,
+    tools: &Tools,
+    macro_name: &str,
+) -> syn::Result<Option<TokenStream>> {
+    let mut attrs = info.attrs.iter().filter(|attr| attr.path.is_ident("kind"));
+    let kind = match attrs.next() {
+        Some(attr) => attr.parse_args::<Ident>()?,
+        None => return Ok(None),
+    };
+    if let Some(attr) = attrs.next() {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "an enum can only have one kind",
+        ));
+    }
+    if info.options.skip_data {
+        return Err(syn::Error::new_spanned(
+            kind,
+            "kind cannot be combined with skip_data because the kind needs every variant",
+        ));
+    }
+
+    let tools = Tools {
+        metadata: false,
+        ..*tools
+    };
+    let name = info.name;
+    let vis = info.vis;
+    let variants = &info.variants;
+    let container_attrs = info.attrs.iter().filter(|attr| {
+        ["rotate", "iter", "shift"]
+            .iter()
+            .any(|name| attr.path.is_ident(name))
+    });
+    let variant_attrs = info.variant_attrs.iter().map(|attrs| {
+        attrs
+            .iter()
+            .filter(|attr| {
+                ["rotate", "name", "doc"]
+                    .iter()
+                    .any(|name| attr.path.is_ident(name))
+            })
+            .collect::<Vec<_>>()
+    });
+    let mut item: DeriveInput = syn::parse2(quote! {
+        #[derive(
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::fmt::Debug,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+            ::core::hash::Hash,
+        )]
+        #(#container_attrs)*
+        #vis enum #kind {
+            #(#(#variant_attrs)* #variants, )*
+        }
+    })?;
+    let impls = tools.expand(&item, macro_name)?;
+    strip_helper_attrs(&mut item);
+
+    Ok(Some(quote! {
+        #item
+        #impls
+
+        #[allow(deprecated)]
+        impl #name {
+            pub fn kind(&self) -> #kind {
+                match *self {
+                    #(Self::#variants { .. } => #kind::#variants, )*
+                }
+            }
+        }
+    }))
+}
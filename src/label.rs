@@ -0,0 +1,66 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, Attribute, Lit, Meta, MetaNameValue};
+
+/// Generates `label()` if any variant has a `#[label = "..."]` attribute.
+/// The variants without one are labeled with their names.
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<Option<TokenStream>> {
+    let mut labels = vec![];
+    let mut any_label = false;
+    for (variant, attrs) in info.variants.iter().zip(&info.variant_attrs) {
+        let label = label(attrs)?;
+        any_label |= label.is_some();
+        labels.push(label.unwrap_or_else(|| variant.unraw().to_string()));
+    }
+    // The variants skipped by `#[rotate(skip_data)]` are labeled on their own, like they are named.
+    let mut skipped_labels = vec![];
+    for skipped in &info.skipped {
+        let label = label(skipped.attrs)?;
+        any_label |= label.is_some();
+        skipped_labels.push(label.unwrap_or_else(|| skipped.ident.unraw().to_string()));
+    }
+    if !any_label {
+        return Ok(None);
+    }
+    let name = info.name;
+    let skipped = info.skipped_idents();
+    let variants = &info.variants;
+
+    Ok(Some(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            pub fn label(&self) -> &'static str {
+                match *self {
+                    #(Self::#variants => #labels, )*
+                    #(Self::#skipped { .. } => #skipped_labels, )*
+                }
+            }
+        }
+    }))
+}
+
+/// Parses the `#[label = "..."]` attribute of a variant, if any.
+fn label(attrs: &[Attribute]) -> syn::Result<Option<String>> {
+    let mut label = None;
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("label")) {
+        if label.is_some() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "a variant can only have one label",
+            ));
+        }
+        match attr.parse_meta()? {
+            Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(lit), ..
+            }) => label = Some(lit.value()),
+            meta => {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    "expected a label like #[label = \"Move up\"]",
+                ))
+            }
+        }
+    }
+    Ok(label)
+}
@@ -46,6 +46,27 @@
 //! Don't you think it should be automated?
 //! This crate provides a `RotateEnum` derive macro to just do this.
 //!
+//! A terminal variant, such as an error state of a state machine, can be marked with
+//! `#[rotate(self_loop)]`. Its `next()` and `prev()` return itself, and the other variants skip over
+//! it. Methods that step several times at once, like `wrapping_add()` and `path_to()`, follow the
+//! same cycle as `next()` and leave the self loops where they are.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+//! enum State {
+//!     Idle,
+//!     Running,
+//!     #[rotate(self_loop)]
+//!     Error,
+//!     Done,
+//! }
+//!
+//! assert_eq!(State::Running.next(), State::Done);
+//! assert_eq!(State::Idle.prev(), State::Done);
+//! assert_eq!(State::Error.next(), State::Error);
+//! ```
+//!
 //! ## Shifting
 //!
 //! This crate also provides [`ShiftEnum`], which will exhaust at the end of the enum list,
@@ -78,6 +99,27 @@
 //!
 //! Note that you can only derive either one of `RotateEnum` or `ShiftEnum`, but not both, because their semantics conflict.
 //!
+//! ## Bouncing
+//!
+//! [`BounceEnum`] goes back and forth instead, turning around at both ends.
+//! Since the variants in the middle can be approached from either side, `bounce()` takes
+//! the direction of the previous step and returns the new direction along with the variant.
+//!
+//! ```
+//! # use rotate_enum::BounceEnum;
+//! # #[derive(BounceEnum, PartialEq, Clone, Copy, Debug)]
+//! # enum Direction {
+//! #     Up,
+//! #     Left,
+//! #     Down,
+//! #     Right,
+//! # }
+//! assert_eq!(Direction::Left.bounce(true), (Direction::Down, true));
+//! assert_eq!(Direction::Right.bounce(true), (Direction::Down, false));
+//! assert_eq!(Direction::Down.bounce(false), (Direction::Left, false));
+//! assert_eq!(Direction::Up.bounce(false), (Direction::Left, true));
+//! ```
+//!
 //! ## Iterating
 //!
 //! This crate also provides [`IterEnum`], which will implement [`Iterator`] object
@@ -108,7 +150,7 @@
 //! assert_eq!(up.iter().collect::<Vec<_>>(), vec![up, left, down, right]);
 //! ```
 //!
-//! Or, you could start from `"YourEnum"Iterator::new()`.
+//! Or, you could start from `"YourEnum"Iterator::new()`, which is also its [`Default`].
 //!
 //! ```
 //! # use rotate_enum::IterEnum;
@@ -124,301 +166,1806 @@
 //! ]);
 //! ```
 //!
+//! A reference to a variant can also be iterated directly, which is the same as calling `iter()`.
+//!
+//! ```
+//! # use rotate_enum::IterEnum;
+//! # #[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+//! # enum Direction {
+//! #     Up,
+//! #     Left,
+//! #     Down,
+//! #     Right,
+//! # }
+//! let mut visited = vec![];
+//! for direction in &Direction::Down {
+//!     visited.push(direction);
+//! }
+//! assert_eq!(visited, vec![Direction::Down, Direction::Right]);
+//! ```
+//!
+//! If you need references rather than values, `iter_refs()` yields `&'static` references
+//! into a static table of the variants.
+//!
+//! ```
+//! # use rotate_enum::IterEnum;
+//! # #[derive(IterEnum, PartialEq, Debug)]
+//! # enum Direction {
+//! #     Up,
+//! #     Left,
+//! #     Down,
+//! #     Right,
+//! # }
+//! let refs: Vec<&'static Direction> = Direction::Down.iter_refs().collect();
+//! assert_eq!(refs, vec![&Direction::Down, &Direction::Right]);
+//! ```
+//!
+//! If you need the position of each variant in the enum along with it, use `iter_indexed()`.
+//! The index is counted from the first variant of the enum, not from the one that started the iterator.
+//!
+//! ```
+//! # use rotate_enum::IterEnum;
+//! # #[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+//! # enum Direction {
+//! #     Up,
+//! #     Left,
+//! #     Down,
+//! #     Right,
+//! # }
+//! assert_eq!(Direction::Down.iter_indexed().collect::<Vec<_>>(), vec![
+//!     (2, Direction::Down), (3, Direction::Right),
+//! ]);
+//! ```
+//!
+//! The iterator knows how many variants it will still yield, which you can get by `remaining()`
+//! or [`ExactSizeIterator::len`].
+//!
+//! ```
+//! # use rotate_enum::IterEnum;
+//! # #[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+//! # enum Direction {
+//! #     Up,
+//! #     Left,
+//! #     Down,
+//! #     Right,
+//! # }
+//! let mut iter = Direction::Left.iter();
+//! assert_eq!(iter.remaining(), 3);
+//! iter.next();
+//! assert_eq!(iter.len(), 2);
+//! ```
+//!
 //! Note that it is not the same as `ShiftEnum` in the sense that the iterator is one-directional, which means you can go only forward and not `prev()`.
 //! It can also be used with iterator methods like `collect()`.
 //!
+//! ## Indexing
 //!
-//! `IterEnum` also requires deriving `Clone`.
+//! Both `RotateEnum` and `ShiftEnum` also generate `COUNT`, `index()` and `from_index()`,
+//! which map variants to their position in the enum and back.
+//! The other generated methods are implemented on top of them, so the generated code
+//! stays small even for enums with hundreds of variants.
 //!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! # #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+//! # enum Direction {
+//! #     Up,
+//! #     Left,
+//! #     Down,
+//! #     Right,
+//! # }
+//! assert_eq!(Direction::COUNT, 4);
+//! assert_eq!(Direction::Down.index(), 2);
+//! assert_eq!(Direction::from_index(2), Some(Direction::Down));
+//! assert_eq!(Direction::from_index(4), None);
+//! ```
 //!
-//! ## Usage
+//! `from_index_wrapping()` maps any integer onto a variant by wrapping it around `COUNT`,
+//! which is handy for hashing or round-robin selection, while `from_index_clamped()`
+//! saturates out-of-range indices to the last variant, e.g. for slider positions.
+//! Both panic if the enum has no variants.
 //!
-//! Use `#[derive(...)]` macro to annotate your enum.
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! # #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+//! # enum Direction {
+//! #     Up,
+//! #     Left,
+//! #     Down,
+//! #     Right,
+//! # }
+//! assert_eq!(Direction::from_index_wrapping(6), Direction::Down);
+//! assert_eq!(Direction::from_index_wrapping(usize::MAX), Direction::Right);
+//! assert_eq!(Direction::from_index_clamped(6), Direction::Right);
+//! ```
 //!
-//! ```rust
-//! use rotate_enum::RotateEnum;
+//! Indices follow the declaration order by default. To keep them stable when variants are
+//! reordered or inserted, e.g. because they are stored or sent over the wire, number every variant
+//! with `#[rotate(ordinal = N)]`. The ordinals must cover `0..COUNT` without gaps, and they also
+//! define the order of rotation and iteration.
 //!
-//! #[derive(RotateEnum)]
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+//! enum Direction {
+//!     #[rotate(ordinal = 0)]
+//!     Up,
+//!     #[rotate(ordinal = 3)]
+//!     Right,
+//!     #[rotate(ordinal = 1)]
+//!     Left,
+//!     #[rotate(ordinal = 2)]
+//!     Down,
+//! }
+//!
+//! assert_eq!(Direction::Right.index(), 3);
+//! assert_eq!(Direction::Right.next(), Direction::Up);
+//! ```
+//!
+//! With an integer `#[repr(...)]` and `#[rotate(repr_conversions)]`, the enum also converts from and into
+//! the integer by the discriminants with `TryFrom` and `to_repr()`, which works for signed types and negative
+//! discriminants as well. `TryFrom` returns the integer back if no variant has it.
+//! The conversions are opt-in, so that an enum can keep its own `TryFrom` of the integer.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! use std::convert::TryFrom;
+//!
+//! #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+//! #[rotate(repr_conversions)]
+//! #[repr(i8)]
+//! enum Status {
+//!     Failed = -2,
+//!     Busy = -1,
+//!     Ok = 0,
+//! }
+//!
+//! assert_eq!(Status::Failed.to_repr(), -2);
+//! assert_eq!(Status::try_from(-1), Ok(Status::Busy));
+//! assert_eq!(Status::try_from(1), Err(1));
+//! assert_eq!(Status::Busy.index(), 1);
+//! ```
+//!
+//! The discriminants must fit in the integer `#[repr(...)]`, counting up from the explicit ones like the compiler does.
+//! The macros report a variant that doesn't fit as an error on the `repr`, which names the variant and the range of the type.
+//!
+//! ```compile_fail
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, Clone, Copy)]
+//! #[repr(i8)]
+//! enum Level {
+//!     Low = 126,
+//!     Mid,
+//!     // error: Level::High has the discriminant 128, but #[repr(i8)] only holds -128 to 127
+//!     High,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, Clone, Copy)]
+//! #[repr(u16)]
+//! enum Offset {
+//!     // error: Offset::Before has the discriminant -1, but #[repr(u16)] only holds 0 to 65535
+//!     Before = -1,
+//!     At,
+//! }
+//! ```
+//!
+//! `count_occurrences()` tallies variants from an iterator into an array indexed by `index()`.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! # #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+//! # enum Direction {
+//! #     Up,
+//! #     Left,
+//! #     Down,
+//! #     Right,
+//! # }
+//! let moves = [Direction::Up, Direction::Up, Direction::Right];
+//! assert_eq!(Direction::count_occurrences(moves.iter().copied()), [2, 0, 0, 1]);
+//! ```
+//!
+//! `for_each()` calls a closure with each variant in the order of their indices,
+//! which is lighter than an iterator for simple side effects like preloading an asset per variant.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! # #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+//! # enum Direction {
+//! #     Up,
+//! #     Left,
+//! #     Down,
+//! #     Right,
+//! # }
+//! let mut visited = vec![];
+//! Direction::for_each(|direction| visited.push(direction));
+//! assert_eq!(visited, [Direction::Up, Direction::Left, Direction::Down, Direction::Right]);
+//! ```
+//!
+//! `map_variants()` builds an array with the result of a closure for each variant,
+//! which can be indexed by `index()`, e.g. for a lookup table built at startup.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! # #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+//! # enum Direction {
+//! #     Up,
+//! #     Left,
+//! #     Down,
+//! #     Right,
+//! # }
+//! let angles = Direction::map_variants(|direction| direction.index() as f64 * 90.0);
+//! assert_eq!(angles[Direction::Down.index()], 180.0);
+//! ```
+//!
+//! [`for_each_variant!`](macro.for_each_variant.html) repeats a block of code for each variant
+//! at compile time, with the variant available as a `const`.
+//! [`rotate_sequence!`](macro.rotate_sequence.html) makes a constant array of consecutive variants
+//! of an enum that rotates.
+//!
+//! ## Combining
+//!
+//! If you want several of these on many enums, [`EnumTools`] can generate any combination of them,
+//! selected with an `#[enum_tools(...)]` attribute.
+//! The available tools are `rotate`, `shift`, `bounce`, `iter`, `count` and `names`.
+//!
+//! ```
+//! # use rotate_enum::EnumTools;
+//! #[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+//! #[enum_tools(rotate, iter, count, names)]
 //! enum Direction {
 //!     Up,
 //!     Left,
 //!     Down,
 //!     Right,
 //! }
+//!
+//! assert_eq!(Direction::Right.next(), Direction::Up);
+//! assert_eq!(Direction::Left.iter().count(), 3);
+//! assert_eq!(Direction::COUNT, 4);
+//! assert_eq!(Direction::Down.name(), "Down");
+//! assert_eq!(Direction::VARIANT_NAMES, ["Up", "Left", "Down", "Right"]);
+//! assert_eq!(Direction::names().nth(1), Some("Left"));
+//! assert_eq!("Left".parse(), Ok(Direction::Left));
+//! assert_eq!(Direction::from_prefix("ri"), Some(Direction::Right));
 //! ```
 //!
+//! The [`rotate_enum!`] macro defines an enum together with rotation, iteration, count and names in one go.
 //!
-//! ## Note
+//! ```
+//! rotate_enum::rotate_enum! {
+//!     #[derive(PartialEq, Clone, Copy, Debug)]
+//!     enum Direction {
+//!         Up,
+//!         Left,
+//!         Down,
+//!         Right,
+//!     }
+//! }
 //!
-//! These macros seem trivial, but it's only possible with procedural macros!
-
-use core::panic;
-
-use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput};
-
-/// This derive macro will implement `next()` and `prev()` methods that rotates
-/// the variant to the annotated enum.
-///
-/// For code examples, see [module-level docs](index.html).
-///
-/// # Requirements
-///
-/// * It must be applied to an enum. Structs are not supported or won't make sense.
-/// * Enums with any associated data are not supported.
-///
-/// # Generated methods
-///
-/// For example, this macro will implement functions like below for
-/// `enum Direction`.
-///
-/// ```
-/// # enum Direction {
-/// #     Up,
-/// #     Left,
-/// #     Down,
-/// #     Right,
-/// # }
-/// impl Direction {
-///     fn next(self) -> Self {
-///         match self {
-///             Self::Up => Self::Left,
-///             Self::Left => Self::Down,
-///             Self::Down => Self::Right,
-///             Self::Right => Self::Up,
-///         }
-///     }
-///
-///     fn prev(self) -> Self {
-///         match self {
-///             Self::Up => Self::Right,
-///             Self::Left => Self::Up,
-///             Self::Down => Self::Left,
-///             Self::Right => Self::Down,
-///         }
-///     }
-/// }
-/// ```
-#[proc_macro_derive(RotateEnum)]
-pub fn rotate_enum(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
-    let name = input.ident;
-
-    let variants = if let Data::Enum(data) = &input.data {
-        data.variants.iter().collect::<Vec<_>>()
-    } else {
-        panic!("derive(RotateEnum) must be applied to an enum");
-    };
-
-    let nexts = variants
-        .iter()
-        .skip(1)
-        .chain(variants.get(0))
-        .map(|v| (&v.ident))
-        .collect::<Vec<_>>();
-
-    let tokens = quote! {
-        impl #name{
-            pub fn next(self) -> Self {
-                match self {
-                    #(Self::#variants => Self::#nexts, )*
-                }
-            }
-            pub fn prev(self) -> Self {
-                match self {
-                    #(Self::#nexts => Self::#variants, )*
-                }
-            }
-        }
-    };
-
-    tokens.into()
-}
-
-/// This derive macro will implement `next()` and `prev()` methods that shifts
-/// the variant to the annotated enum.
-///
-/// * `next()` will return `Some(Variant)` where `Variant` is next one in the enum, or `None` if it was the last variant of the enum.
-/// * `prev()` will return `Some(Variant)` where `Variant` is previous one in the enum, or `None` if it was the first variant of the enum.
-///
-/// For code examples, see [module-level docs](index.html).
-///
-/// # Requirements
-///
-/// * It must be applied to an enum. Structs are not supported or won't make sense.
-/// * Enums with any associated data are not supported.
-///
-/// # Generated methods
-///
-/// For example, this macro will implement functions like below for
-/// `enum Direction`.
-///
-/// ```
-/// # enum Direction {
-/// #     Up,
-/// #     Left,
-/// #     Down,
-/// #     Right,
-/// # }
-/// impl Direction {
-///     fn next(self) -> Option<Self> {
-///         match self {
-///             Self::Up => Some(Self::Left),
-///             Self::Left => Some(Self::Down),
-///             Self::Down => Some(Self::Right),
-///             Self::Right => None,
-///         }
-///     }
-///
-///     fn prev(self) -> Option<Self> {
-///         match self {
-///             Self::Up => None,
-///             Self::Left => Some(Self::Up),
-///             Self::Down => Some(Self::Left),
-///             Self::Right => Some(Self::Down),
-///         }
-///     }
-/// }
-/// ```
-#[proc_macro_derive(ShiftEnum)]
-pub fn shift_enum(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
-    let name = input.ident;
-
-    let variants = if let Data::Enum(data) = &input.data {
-        data.variants.iter().collect::<Vec<_>>()
-    } else {
-        panic!("derive(RotateEnum) must be applied to an enum");
-    };
-
-    let nexts = variants
-        .iter()
-        .skip(1)
-        .map(|v| quote! { Some(Self::#v) })
-        .chain(Some(quote! { None }))
-        .collect::<Vec<_>>();
-
-    let none_quote = Some(quote! { None });
-    let prevs = variants
-        .iter()
-        .take(variants.len() - 1)
-        .map(|v| quote! { Some(Self::#v) })
-        .collect::<Vec<_>>();
-
-    let prevs = none_quote.iter().chain(&prevs).collect::<Vec<_>>();
-
-    let tokens = quote! {
-        impl #name{
-            pub fn next(self) -> Option<Self> {
-                match self {
-                    #(Self::#variants => #nexts, )*
-                }
-            }
-            pub fn prev(self) -> Option<Self> {
-                match self {
-                    #(Self::#variants => #prevs, )*
-                }
-            }
-        }
-    };
-
-    tokens.into()
-}
-
-/// This derive macro will implement `iter()` method to the annotated enum that sequentially
-/// yield the variant of the enum.
-///
-/// For code examples, see [module-level docs](index.html).
-///
-/// # Requirements
+//! assert_eq!(Direction::Up.prev(), Direction::Right);
+//! ```
+//!
+//! If you prefer an attribute over a function-like macro, [`enumeration`] does the same to an ordinary enum definition.
+//! It also removes the per-variant metadata attributes it reads, so they cannot clash with other derives.
+//!
+//! ```
+//! #[rotate_enum::enumeration(rotate, names)]
+//! #[derive(PartialEq, Clone, Copy, Debug)]
+//! enum Direction {
+//!     Up,
+//!     Left,
+//!     Down,
+//!     Right,
+//! }
+//!
+//! assert_eq!(Direction::Up.prev().name(), "Right");
+//! ```
+//!
+//! ## Options
+//!
+//! All of the macros accept options in a `#[rotate(...)]` attribute on the enum.
+//!
+//! * `self_test` - generates `#[cfg(test)]` unit tests that check the generated methods
+//!   for this enum, e.g. that `next()` and `prev()` are inverses of each other and that
+//!   the iterator visits every variant exactly once. The enum must be declared at module level.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, Clone, Copy)]
+//! #[rotate(self_test)]
+//! enum Direction {
+//!     Up,
+//!     Left,
+//!     Down,
+//!     Right,
+//! }
+//! ```
+//!
+//! * `kani` - generates `#[cfg(kani)]` proof harnesses for the [Kani](https://github.com/model-checking/kani)
+//!   model checker, which check for every index that `from_index()` and `index()` round-trip and that `next()` and `prev()`
+//!   are inverses of each other, instead of the indices a test happens to try. Run them with `cargo kani`.
+//!   Like `self_test`, the enum must be declared at module level. Newer Rust warns about the unknown `kani` cfg
+//!   unless it is declared in `Cargo.toml`:
+//!
+//! ```toml
+//! [lints.rust]
+//! unexpected_cfgs = { level = "warn", check-cfg = ['cfg(kani)'] }
+//! ```
+//!
+//! * `table` - generates `"YourEnum"Table<T>`, an array with one value per variant that is indexed by the enum.
+//!   It can be constructed in a `const` or `static`, and the number of values is checked at compile time.
+//!   It is generated by the macros that generate `COUNT`, i.e. not by `IterEnum`.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, Clone, Copy)]
+//! #[rotate(table)]
+//! enum Direction {
+//!     Up,
+//!     Left,
+//!     Down,
+//!     Right,
+//! }
+//!
+//! static SPEEDS: DirectionTable<f32> = DirectionTable::new([1.0, 2.0, 3.0, 4.0]);
+//!
+//! assert_eq!(SPEEDS[Direction::Down], 3.0);
+//! assert_eq!(SPEEDS.get(Direction::Up.prev()), &4.0);
+//! ```
+//!
+//! [`enum_table!`](macro.enum_table.html) builds the table from the values named by the variants instead,
+//! which is checked to give every variant exactly once.
+//!
+//! * `default` - implements `Default` by returning the first variant, so the start of the cycle
+//!   and the default value cannot drift apart. Use `default = "Variant"` to pick another variant.
+//!   Like `table`, it is generated by the macros that generate `COUNT`.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+//! #[rotate(default = "Down")]
+//! enum Direction {
+//!     Up,
+//!     Left,
+//!     Down,
+//!     Right,
+//! }
+//!
+//! assert_eq!(Direction::default(), Direction::Down);
+//! ```
+//!
+//! * `debug` - prints the code generated for this enum while compiling, which is easier than
+//!   expanding the whole crate when something goes wrong. Each macro on the enum prints its own part.
+//!   The output is not formatted, but it can be piped through `rustfmt` to make it readable.
+//! * `hidden_module` - declares each generated type, such as `"YourEnum"Iterator`, together with its impls
+//!   in a `#[doc(hidden)]` module like `__rotate_enum_your_enum_iterator`, and re-exports it with a glob import
+//!   and the visibility of the enum. Your own items of the same name shadow the re-export instead of colliding with it,
+//!   and the generated type stays reachable through its module. The enum must be declared at module level.
+//!
+//! * `aliases = "next_prev"` - generates aliases of `next()` and `prev()` named `rotate_next` and
+//!   `rotate_prev` with the given suffixes, so that the methods can use the terms of your domain.
+//!   It is read by `RotateEnum` and the `rotate` tool.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+//! #[rotate(aliases = "cw_ccw")]
+//! enum Direction {
+//!     Up,
+//!     Right,
+//!     Down,
+//!     Left,
+//! }
+//!
+//! assert_eq!(Direction::Up.rotate_cw(), Direction::Right);
+//! assert_eq!(Direction::Up.rotate_ccw(), Direction::Left);
+//! ```
+//!
+//! * `description` - generates `description()`, which returns the doc comment of the variant
+//!   with the leading space of each line removed, or an empty string if it has none.
+//!   This lets help screens and tooltips reuse the documentation. Like `table`, it is generated
+//!   by the macros that generate `COUNT`.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+//! #[rotate(description)]
+//! enum Tool {
+//!     /// Draws freehand lines.
+//!     Pen,
+//!     /// Removes what was drawn.
+//!     ///
+//!     /// Hold shift to erase everything.
+//!     Eraser,
+//!     Select,
+//! }
+//!
+//! assert_eq!(Tool::Pen.description(), "Draws freehand lines.");
+//! assert_eq!(Tool::Pen.next().description(), "Removes what was drawn.\n\nHold shift to erase everything.");
+//! assert_eq!(Tool::Select.description(), "");
+//! ```
+//!
+//! * `true_variant = "Variant"` - picks the variant that converts from and into `true`.
+//!   The macros that generate `COUNT` implement `From<bool>` and `From<"YourEnum"> for bool`
+//!   for every enum with exactly two variants, where the first one is `false` and the second one is `true`
+//!   unless this option says otherwise. `Not` is implemented for these enums too, to flip them with `!`.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+//! #[rotate(true_variant = "On")]
+//! enum Switch {
+//!     On,
+//!     Off,
+//! }
+//!
+//! assert_eq!(Switch::from(true), Switch::On);
+//! assert!(!bool::from(Switch::Off));
+//! assert!(!bool::from(Switch::On.toggle()));
+//! ```
+//!
+//! * `group` - treats the variants of an enum that rotates as the rotations they stand for, i.e. the integers
+//!   modulo `COUNT`. `+` composes two rotations, `IDENTITY` is the first variant, which doesn't rotate,
+//!   and `inverse()` returns the rotation that undoes the variant, e.g. for direction math on a hex grid.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+//! #[rotate(group)]
+//! enum Turn {
+//!     Straight,
+//!     Right,
+//!     Back,
+//!     Left,
+//! }
+//!
+//! assert_eq!(Turn::Right + Turn::Back, Turn::Left);
+//! assert_eq!(Turn::Right + Turn::Right.inverse(), Turn::IDENTITY);
+//! ```
+//!
+//! * `skip_data` - leaves the variants with fields out, so that an enum with a few data variants can
+//!   rotate, iterate and count among its unit variants only. A data variant has no index of its own,
+//!   so rotating bridges over it: `next()` and `prev()` go to the unit variants declared after and before it,
+//!   and it shares the index of the one after it with the other generated methods, like `iter()` and `value()`.
+//!   Its own name is used by `name()`, `label()`, `description()` and the formatting integrations.
+//!   It cannot be combined with `#[kind(...)]` or `#[iter(flatten)]`, which cover the data variants instead,
+//!   or with `repr_conversions`.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+//! #[rotate(skip_data)]
+//! enum Tool {
+//!     Pen,
+//!     Stamp(char),
+//!     Eraser,
+//! }
+//!
+//! assert_eq!(Tool::COUNT, 2);
+//! assert_eq!(Tool::Pen.next(), Tool::Eraser);
+//! assert_eq!(Tool::Stamp('x').next(), Tool::Eraser);
+//! assert_eq!(Tool::Stamp('x').prev(), Tool::Pen);
+//! ```
+//!
+//! Without it, or `#[kind(...)]` or `#[iter(flatten)]`, a data variant is an error that suggests them:
+//!
+//! ```compile_fail
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+//! enum Tool {
+//!     Pen,
+//!     // error: derive(RotateEnum) cannot generate methods for Stamp, which carries data; ...
+//!     Stamp(char),
+//!     Eraser,
+//! }
+//! ```
+//!
+//! * `wasm_bindgen` - exports the generated methods to JavaScript with `#[wasm_bindgen]`. wasm-bindgen cannot export
+//!   methods of enums, so they are wrapped in free functions named after the enum, e.g. `direction_next(value)`,
+//!   `direction_prev(value)`, `direction_index(value)`, `direction_from_index(index)` and `direction_count()`,
+//!   which can also be used to iterate over the variants. The functions are as visible as the enum.
+//!   The enum itself needs `#[wasm_bindgen]`, and your crate needs to depend on wasm-bindgen.
+//!
+//! * `repr_conversions` - implements `TryFrom` of the integer `#[repr(...)]` of the enum and generates `to_repr()`,
+//!   which convert by the discriminants as described in [Indexing](#indexing).
+//! * `maps_to = "OtherEnum"` - converts the enum from and into another enum with `From`, pairing up the
+//!   variants with the same index, e.g. an internal enum and the one used on the wire. The other enum
+//!   must also derive one of the macros that generate `COUNT`, and a different number of variants is
+//!   a compile error. The option can be given more than once.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+//! #[rotate(maps_to = "Heading")]
+//! enum Direction {
+//!     Up,
+//!     Left,
+//!     Down,
+//!     Right,
+//! }
+//!
+//! #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+//! enum Heading {
+//!     North,
+//!     West,
+//!     South,
+//!     East,
+//! }
+//!
+//! assert_eq!(Heading::from(Direction::Left), Heading::West);
+//! assert_eq!(Direction::from(Heading::East.next()), Direction::Up);
+//! ```
+//!
+//! * `cfg(...)` and `cfg_attr(...)` - are added to every generated item, e.g. to only generate the methods
+//!   with a feature of your crate. They can be given more than once, and the enum itself is not affected.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! #[derive(RotateEnum, Clone, Copy)]
+//! #[rotate(cfg(feature = "cycling"))]
+//! enum Direction {
+//!     Up,
+//!     Left,
+//!     Down,
+//!     Right,
+//! }
+//! ```
+//!
+//! ## Runtime support
+//!
+//! With `#[rotate(runtime)]`, the macros that generate `COUNT` also implement the `Rotate` trait
+//! of the [rotate-enum-runtime](https://crates.io/crates/rotate-enum-runtime) crate, which provides generic types built on it.
+//! For example, `AtomicEnum<T>` stores a variant in an atomic integer, so that it can be rotated
+//! with `fetch_next()` and `fetch_prev()` from several threads or an interrupt handler without a mutex.
+//! It is as small as the enum allows, e.g. an `AtomicU8` for up to 256 variants. On targets without compare-and-swap
+//! like `thumbv6m-none-eabi`, disable the default features of rotate-enum-runtime to keep only `load()` and `store()`.
+//! The macros also convert the enum from and into `CyclicIndex<N>`, where `N` is `COUNT`, an index whose
+//! arithmetic wraps around, so numeric algorithms can work on indices and convert the result back.
+//! `EnumVariants` lists the variants of any of these enums, e.g. for a generic settings UI.
+//! `RoundRobin<T>` cycles through the variants forever, and variants can be removed from and added back
+//! to the rotation, e.g. to schedule subsystems that can be paused.
+//! The macros that generate `iter()` implement `IntoEnumIterator`, so that generic code can iterate over
+//! any of these enums without naming the generated iterator type.
+//! Like the integrations below, the option is given per enum, so crates in the same build that don't depend on
+//! rotate-enum-runtime are not affected.
+//!
+//! ```toml
+//! [dependencies]
+//! rotate-enum = "0.1"
+//! rotate-enum-runtime = "0.1"
+//! ```
+//!
+//! The generated code refers to the runtime crate as `::rotate_enum_runtime`. If your crate only reaches it through
+//! a re-export, e.g. of a framework that wraps rotate-enum, give the path of the re-export with `#[rotate(crate = "...")]`.
+//!
+//! ```
+//! # use rotate_enum::RotateEnum;
+//! # mod myfw { pub mod macros { pub use rotate_enum_runtime as runtime; } }
+//! #[derive(RotateEnum, Clone, Copy)]
+//! #[rotate(runtime, crate = "myfw::macros::runtime")]
+//! enum Direction {
+//!     Up,
+//!     Left,
+//!     Down,
+//!     Right,
+//! }
+//! ```
+//!
+//! ## Integrations
+//!
+//! The macros that generate `COUNT` can also generate code that works with other crates,
+//! each enabled on the enums that need it with a `#[rotate(...)]` option of the same name, e.g. `#[rotate(defmt)]`.
+//! Your crate needs to depend on those crates itself. Since the options are given per enum, other crates in the
+//! same build that also use rotate-enum don't need those dependencies.
+//!
+//! * `defmt` - implements `defmt::Format` with the variant names, so that no_std firmware can log the enum.
+//! * `ufmt` - implements `ufmt::uDisplay` with the variant names, so that firmware can print the enum
+//!   without the code size of `core::fmt`.
+//! * `rkyv` - implements `Archive`, `Serialize` and `Deserialize` of rkyv 0.7. The enum is archived
+//!   as the index of the variant in a `u32`, so the archive stays valid as long as the variants keep their indices.
+//!   Deserializing an index out of range returns an error like `DirectionArchiveError` through the deserializer,
+//!   so its error type needs to convert from that, which `rkyv::Infallible` cannot.
+//!   rkyv's `Archive` has an `unsafe` method, so this is the only option that generates `unsafe` code.
+//!   Everything else can be used in crates with `#![forbid(unsafe_code)]`.
+//! * `schemars` - implements `JsonSchema` of schemars 0.8 as a string that is one of the variant names,
+//!   which is how serde represents the variants by default, so that configs containing the enum can be validated.
+//! * `ts_rs` - implements `TS` of ts-rs 7 as a union of the variant names, like `"Up" | "Left" | "Down" | "Right"`,
+//!   so that types deriving `TS` can contain the enum and the option lists of a web client stay in sync with it.
+//!   `decl()` returns the declaration of the type itself.
+//! * `num_traits` - implements `Bounded`, `FromPrimitive` and `ToPrimitive` of num-traits 0.2,
+//!   so that numeric-generic code and parsers written against them can work with the enum.
+//!   The first variant is the minimum and the last one is the maximum. The enum converts from and into
+//!   the indices of the variants, or their discriminants if any variant declares one.
+//! * `enum_map` - implements `Enum` and `EnumArray` of enum-map 2, so that the enum can be the key
+//!   of an `EnumMap` without deriving `Enum` as well. The map is ordered by the indices of the variants.
+//! * `rand` - generates methods that take a random number generator of rand 0.8. The enums that rotate get
+//!   `random_step(rng)`, which calls `next()` or `prev()` at random, e.g. for wandering monsters in a roguelike.
+//!   `derive(EnumTools)` generates `random_weighted(rng)` for the enums with `#[weight(...)]` on the variants.
+//!   Every one of these enums gets `shuffled_iter(rng)`, which yields every variant once in a random order, e.g. for quizzes.
+//!
+//! ## Minimum supported Rust version
+//!
+//! The crates and the code generated by the macros build with Rust 1.56, given dependencies that support it.
+//! A few features generate newer code by default where it gives better errors, e.g. `maps_to` checks
+//! the number of variants with a panic in a constant, which needs Rust 1.57. Enable the `msrv` feature to
+//! generate code for Rust 1.56 instead, at the cost of those error messages.
+//!
+//! ```toml
+//! [dependencies]
+//! rotate-enum = { version = "0.1", features = ["msrv"] }
+//! ```
+//!
+//! ## Usage
+//!
+//! Use `#[derive(...)]` macro to annotate your enum.
+//!
+//! ```rust
+//! use rotate_enum::RotateEnum;
+//!
+//! #[derive(RotateEnum)]
+//! enum Direction {
+//!     Up,
+//!     Left,
+//!     Down,
+//!     Right,
+//! }
+//! ```
+//!
+//!
+//! ## Note
+//!
+//! These macros seem trivial, but it's only possible with procedural macros!
+
+mod boolean;
+mod bounce;
+mod category;
+mod cfg;
+mod default;
+mod defmt;
+mod description;
+mod enum_map;
+mod flatten;
+mod for_each;
+mod group;
+mod index;
+mod iter;
+mod kani;
+mod key;
+mod kind;
+mod label;
+mod maps_to;
+mod model;
+mod names;
+mod num_traits;
+mod pair;
+mod rand;
+mod repr;
+mod rkyv;
+mod rotate;
+mod runtime;
+mod schemars;
+mod self_test;
+mod sequence;
+mod shift;
+mod subset;
+mod table;
+mod tools;
+mod ts_rs;
+mod ufmt;
+mod value;
+mod wasm_bindgen;
+mod weight;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, AttributeArgs, DeriveInput};
+use tools::{strip_helper_attrs, Tools};
+
+fn derive(input: TokenStream, tools: Tools, macro_name: &str) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    tools
+        .expand(&input, macro_name)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Emits the enum itself along with the generated code, for the macros that take over its definition.
+fn define(mut input: DeriveInput, tools: syn::Result<Tools>, macro_name: &str) -> TokenStream {
+    let expanded = tools
+        .and_then(|tools| tools.expand(&input, macro_name))
+        .unwrap_or_else(|e| e.to_compile_error());
+    strip_helper_attrs(&mut input);
+
+    quote! {
+        #input
+        #expanded
+    }
+    .into()
+}
+
+/// This derive macro will implement `next()` and `prev()` methods that rotates
+/// the variant to the annotated enum.
+///
+/// For code examples, see [module-level docs](index.html).
+///
+/// # Requirements
+///
+/// * It must be applied to an enum. Structs are not supported or won't make sense.
+/// * Enums with any associated data are not supported, except with `#[rotate(skip_data)]`.
+/// * The variants must fit in an integer `#[repr(...)]`, see [Indexing](index.html#indexing).
+///
+/// # Additional methods
+///
+/// * `wrapping_add(n)` and `wrapping_sub(n)` rotate by `n` steps at once, like calling `next()` or `prev()` `n` times,
+///   but in constant time.
+///
+/// ```
+/// # use rotate_enum::RotateEnum;
+/// # #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// assert_eq!(Direction::Left.wrapping_add(2), Direction::Right);
+/// assert_eq!(Direction::Left.wrapping_add(7), Direction::Up);
+/// assert_eq!(Direction::Left.wrapping_sub(2), Direction::Right);
+/// ```
+///
+/// * `advance_wrapping(n)` rotates like `wrapping_add(n)` and also returns how many times
+///   it wrapped around past the last variant, e.g. to count the turns of a rotary encoder.
+///
+/// ```
+/// # use rotate_enum::RotateEnum;
+/// # #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// assert_eq!(Direction::Left.advance_wrapping(2), (Direction::Right, 0));
+/// assert_eq!(Direction::Left.advance_wrapping(11), (Direction::Up, 3));
+/// ```
+///
+/// * `neighbors()` returns the previous and the next variant at once, as `(prev, next)`.
+///
+/// ```
+/// # use rotate_enum::RotateEnum;
+/// # #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// assert_eq!(Direction::Up.neighbors(), (Direction::Right, Direction::Left));
+/// ```
+///
+/// * `cmp_from(anchor, other)` compares two variants by how many `next()` steps they are away from `anchor`,
+///   which lets you sort variants starting from any of them.
+///
+/// ```
+/// # use rotate_enum::RotateEnum;
+/// # #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// let mut directions = vec![Direction::Up, Direction::Left, Direction::Down, Direction::Right];
+/// directions.sort_by(|a, b| a.cmp_from(Direction::Down, *b));
+/// assert_eq!(directions, vec![Direction::Down, Direction::Right, Direction::Up, Direction::Left]);
+/// ```
+///
+/// * `path_to(target)` iterates over the variants that `next()` steps through to reach `target`,
+///   ending with `target` itself, e.g. to animate between two states one step at a time.
+///   `shortest_path_to(target)` steps in whichever direction is shorter, preferring `next()` on a tie.
+///   Both are empty if `target` is the variant itself.
+///
+/// ```
+/// # use rotate_enum::RotateEnum;
+/// # #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// let path: Vec<_> = Direction::Left.path_to(Direction::Up).collect();
+/// assert_eq!(path, vec![Direction::Down, Direction::Right, Direction::Up]);
+/// let path: Vec<_> = Direction::Left.shortest_path_to(Direction::Up).collect();
+/// assert_eq!(path, vec![Direction::Up]);
+/// ```
+///
+/// * `by_distance_from(anchor)` iterates over all the variants ordered by how many steps in either direction
+///   they are away from `anchor`, starting with `anchor` itself and preferring `next()` on a tie,
+///   e.g. to try the closest alternatives first when a setting is not available.
+///
+/// ```
+/// # use rotate_enum::RotateEnum;
+/// # #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// let order: Vec<_> = Direction::by_distance_from(Direction::Left).collect();
+/// assert_eq!(order, vec![Direction::Left, Direction::Down, Direction::Up, Direction::Right]);
+/// ```
+///
+/// * `all_rotations()` iterates over every variant as a starting point, yielding for each of them
+///   an iterator over all the variants in index order, starting there and wrapping around,
+///   e.g. to test that some logic gives the same result whichever way the enum is rotated.
+///
+/// ```
+/// # use rotate_enum::RotateEnum;
+/// # #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// let rotations: Vec<Vec<_>> = Direction::all_rotations().map(|rotation| rotation.collect()).collect();
+/// assert_eq!(rotations.len(), 4);
+/// assert_eq!(rotations[2], vec![Direction::Down, Direction::Right, Direction::Up, Direction::Left]);
+/// ```
+///
+/// # Small enums
+///
+/// An enum with a single variant rotates to itself in both directions.
+/// An enum with exactly two variants additionally gets `toggle()`, which is the same as `next()`
+/// but reads better for on/off style enums, and `!` flips it in the same way. It also converts from and into `bool`,
+/// see the `true_variant` option in the [module-level docs](index.html).
+///
+/// ```
+/// # use rotate_enum::RotateEnum;
+/// #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+/// enum Switch {
+///     On,
+///     Off,
+/// }
+///
+/// assert_eq!(Switch::On.toggle(), Switch::Off);
+/// assert_eq!(Switch::Off.toggle(), Switch::On);
+/// assert_eq!(!Switch::On, Switch::Off);
+/// ```
+///
+/// # Generated methods
+///
+/// For example, this macro will implement functions like below for
+/// `enum Direction`.
+///
+/// ```
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// impl Direction {
+///     pub const COUNT: usize = 4;
+///
+///     pub fn index(&self) -> usize {
+///         match *self {
+///             Self::Up => 0,
+///             Self::Left => 1,
+///             Self::Down => 2,
+///             Self::Right => 3,
+///         }
+///     }
+///
+///     pub fn from_index(index: usize) -> Option<Self> {
+///         match index {
+///             0 => Some(Self::Up),
+///             1 => Some(Self::Left),
+///             2 => Some(Self::Down),
+///             3 => Some(Self::Right),
+///             _ => None,
+///         }
+///     }
+///
+///     pub fn next(self) -> Self {
+///         let index = self.index() + 1;
+///         Self::from_index(if index < Self::COUNT { index } else { 0 }).unwrap()
+///     }
+///
+///     pub fn prev(self) -> Self {
+///         let index = self.index();
+///         Self::from_index(if 0 < index { index } else { Self::COUNT } - 1).unwrap()
+///     }
+/// }
+/// ```
+#[proc_macro_derive(RotateEnum, attributes(rotate))]
+pub fn rotate_enum_derive(input: TokenStream) -> TokenStream {
+    let tools = Tools {
+        rotate: true,
+        ..Tools::default()
+    };
+    derive(input, tools, "derive(RotateEnum)")
+}
+
+/// This derive macro will implement `next()` and `prev()` methods that shifts
+/// the variant to the annotated enum.
+///
+/// * `next()` will return `Some(Variant)` where `Variant` is next one in the enum, or `None` if it was the last variant of the enum.
+/// * `prev()` will return `Some(Variant)` where `Variant` is previous one in the enum, or `None` if it was the first variant of the enum.
+///
+/// For code examples, see [module-level docs](index.html).
+///
+/// # Requirements
+///
+/// * It must be applied to an enum. Structs are not supported or won't make sense.
+/// * Enums with any associated data are not supported, except with `#[rotate(skip_data)]`.
+/// * The variants must fit in an integer `#[repr(...)]`, see [Indexing](index.html#indexing).
+///
+/// # Additional methods
+///
+/// * `next_or(default)` and `prev_or(default)` return `default` instead of `None` at the end.
+///
+/// ```
+/// # use rotate_enum::ShiftEnum;
+/// # #[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// assert_eq!(Direction::Left.next_or(Direction::Up), Direction::Down);
+/// assert_eq!(Direction::Right.next_or(Direction::Up), Direction::Up);
+/// assert_eq!(Direction::Up.prev_or(Direction::Right), Direction::Right);
+/// ```
+///
+/// * `checked_add(n)` and `checked_sub(n)` shift by `n` steps at once, which may be negative,
+///   and return `None` if that would go past either end of the enum.
+///
+/// ```
+/// # use rotate_enum::ShiftEnum;
+/// # #[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// assert_eq!(Direction::Left.checked_add(2), Some(Direction::Right));
+/// assert_eq!(Direction::Left.checked_add(3), None);
+/// assert_eq!(Direction::Left.checked_add(-1), Some(Direction::Up));
+/// assert_eq!(Direction::Left.checked_sub(2), None);
+/// ```
+///
+/// * `saturating_add(n)` and `saturating_sub(n)` shift by `n` steps at once too,
+///   but stop at the first or the last variant instead of returning `None`.
+///
+/// ```
+/// # use rotate_enum::ShiftEnum;
+/// # #[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// assert_eq!(Direction::Left.saturating_add(10), Direction::Right);
+/// assert_eq!(Direction::Left.saturating_sub(10), Direction::Up);
+/// ```
+///
+/// * `shift_by(n)` shifts like `saturating_add(n)` and also returns how many steps
+///   were actually taken, e.g. to find out how much of a scroll gesture was consumed.
+///
+/// ```
+/// # use rotate_enum::ShiftEnum;
+/// # #[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// assert_eq!(Direction::Left.shift_by(10), (Direction::Right, 2));
+/// assert_eq!(Direction::Left.shift_by(-1), (Direction::Up, -1));
+/// ```
+///
+/// * `remaining_forward()` and `remaining_backward()` return how many times `next()` or `prev()`
+///   can be called before reaching the end, e.g. for the progress of a wizard.
+///
+/// ```
+/// # use rotate_enum::ShiftEnum;
+/// # #[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// assert_eq!(Direction::Left.remaining_forward(), 2);
+/// assert_eq!(Direction::Left.remaining_backward(), 1);
+/// ```
+///
+/// # Overflow
+///
+/// With `#[shift(overflow_to = "Variant")]`, `next()` past the last variant and `prev()` before the first one
+/// return the given variant instead of `None`, e.g. to return to a summary after the last step of a wizard.
+/// The other methods still stop at the ends.
+///
+/// ```
+/// # use rotate_enum::ShiftEnum;
+/// #[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+/// #[shift(overflow_to = "Summary")]
+/// enum Step {
+///     Summary,
+///     Name,
+///     Address,
+/// }
+///
+/// assert_eq!(Step::Name.next(), Some(Step::Address));
+/// assert_eq!(Step::Address.next(), Some(Step::Summary));
+/// assert_eq!(Step::Summary.prev(), Some(Step::Summary));
+/// ```
+///
+/// # Iterating in place
+///
+/// With `#[shift(iterator)]`, the enum implements `Iterator` itself instead of having an inherent `next()`.
+/// `next()` then advances the variant in place and returns the new one, so that a state can drive
+/// a `while let` loop without a separate iterator. It takes `&mut self`, so a variable needs to be `mut`
+/// to call it, and methods of `Iterator` such as `max()` may become ambiguous with those of `Ord`.
+///
+/// ```
+/// # use rotate_enum::ShiftEnum;
+/// #[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+/// #[shift(iterator)]
+/// enum Stage {
+///     Boot,
+///     Load,
+///     Run,
+/// }
+///
+/// let mut stage = Stage::Boot;
+/// while let Some(next) = stage.next() {
+///     println!("entering {:?}", next);
+/// }
+/// assert_eq!(stage, Stage::Run);
+/// ```
+///
+/// # Generated methods
+///
+/// For example, this macro will implement functions like below for
+/// `enum Direction`. `COUNT`, `index()` and `from_index()` are the same as
+/// the ones generated by [`RotateEnum`](derive.RotateEnum.html).
+///
+/// ```
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// # impl Direction {
+/// #     fn index(&self) -> usize { 0 }
+/// #     fn from_index(index: usize) -> Option<Self> { None }
+/// # }
+/// impl Direction {
+///     pub fn next(self) -> Option<Self> {
+///         Self::from_index(self.index() + 1)
+///     }
+///
+///     pub fn prev(self) -> Option<Self> {
+///         self.index().checked_sub(1).and_then(Self::from_index)
+///     }
+/// }
+/// ```
+#[proc_macro_derive(ShiftEnum, attributes(rotate, shift))]
+pub fn shift_enum(input: TokenStream) -> TokenStream {
+    let tools = Tools {
+        shift: true,
+        ..Tools::default()
+    };
+    derive(input, tools, "derive(ShiftEnum)")
+}
+
+/// This derive macro will implement `bounce()` method that steps through the variants
+/// back and forth, turning around at both ends, like a triangle wave.
+///
+/// For code examples, see [module-level docs](index.html).
+///
+/// Since the variants in the middle are visited in both directions, the next variant depends
+/// on the direction it is approached from, unlike `next()` of [`RotateEnum`](derive.RotateEnum.html).
+/// So `bounce(forward)` takes the direction of the previous step and returns the direction of
+/// the step it took along with the variant, which you can pass to the next call.
+///
+/// # Requirements
+///
+/// * It must be applied to an enum. Structs are not supported or won't make sense.
+/// * Enums with any associated data are not supported, except with `#[rotate(skip_data)]`.
+/// * The variants must fit in an integer `#[repr(...)]`, see [Indexing](index.html#indexing).
+/// * It generates `COUNT`, `index()` and `from_index()` like `RotateEnum` and `ShiftEnum`,
+///   so it cannot be derived together with either of them. Use [`EnumTools`](derive.EnumTools.html)
+///   with `rotate` and `bounce` if you need both.
+///
+/// # Additional methods
+///
+/// * `bounce_iter(forward)` iterates over the variants that `bounce()` steps through endlessly,
+///   e.g. to animate a palette.
+///
+/// ```
+/// # use rotate_enum::BounceEnum;
+/// # #[derive(BounceEnum, PartialEq, Clone, Copy, Debug)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// let frames: Vec<_> = Direction::Left.bounce_iter(true).take(4).collect();
+/// assert_eq!(frames, vec![Direction::Down, Direction::Right, Direction::Down, Direction::Left]);
+/// ```
+///
+/// An enum with a single variant bounces to itself without changing the direction.
+#[proc_macro_derive(BounceEnum, attributes(rotate))]
+pub fn bounce_enum(input: TokenStream) -> TokenStream {
+    let tools = Tools {
+        bounce: true,
+        ..Tools::default()
+    };
+    derive(input, tools, "derive(BounceEnum)")
+}
+
+/// This derive macro will implement `iter()` method to the annotated enum that sequentially
+/// yield the variant of the enum.
+///
+/// For code examples, see [module-level docs](index.html).
+///
+/// # Requirements
 ///
 /// * It must be applied to an enum. Structs are not supported or won't make sense.
-/// * Enums with any associated data are not supported.
-/// * Enum also needs to derive [`Clone`].
+/// * Enums with any associated data are not supported, except with `#[rotate(skip_data)]`.
+/// * The variants must fit in an integer `#[repr(...)]`, see [Indexing](index.html#indexing).
+///
+/// # Starting variant
+///
+/// `"YourEnum"Iterator::new()` yields every variant starting from the first one.
+/// You can start from another variant with `#[iter(start = "Variant")]`, in which case
+/// the iterator wraps around to yield the variants before it at the end.
+///
+/// ```
+/// # use rotate_enum::IterEnum;
+/// #[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+/// #[iter(start = "Down")]
+/// enum Direction {
+///     Up,
+///     Left,
+///     Down,
+///     Right,
+/// }
+///
+/// assert_eq!(DirectionIterator::new().collect::<Vec<_>>(), vec![
+///     Direction::Down, Direction::Right, Direction::Up, Direction::Left,
+/// ]);
+/// ```
+///
+/// # Flattening
+///
+/// With `#[iter(flatten)]`, variants may have a single unnamed field whose type is another enum deriving `IterEnum`.
+/// The iterator then yields each unit variant once and each variant with a field once for every variant of the field,
+/// in order of declaration.
+/// Only `"YourEnum"Iterator` is generated in this case, since a variant with a field cannot be used as a starting point.
+///
+/// ```
+/// # use rotate_enum::IterEnum;
+/// #[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+/// enum FileItem {
+///     Open,
+///     Save,
+/// }
+///
+/// #[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+/// enum EditItem {
+///     Copy,
+///     Paste,
+/// }
+///
+/// #[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+/// #[iter(flatten)]
+/// enum Menu {
+///     File(FileItem),
+///     Edit(EditItem),
+///     Quit,
+/// }
+///
+/// assert_eq!(MenuIterator::new().collect::<Vec<_>>(), vec![
+///     Menu::File(FileItem::Open),
+///     Menu::File(FileItem::Save),
+///     Menu::Edit(EditItem::Copy),
+///     Menu::Edit(EditItem::Paste),
+///     Menu::Quit,
+/// ]);
+/// ```
 ///
 /// # Generated methods
 ///
 /// For example, this macro will implement an iterator and methods like below for
-/// `enum Direction`.
+/// `enum Direction`. The iterator has the same visibility as the enum.
+/// It only keeps the index of the next variant to yield and the number of variants left,
+/// so it can be derived together with either [`RotateEnum`](derive.RotateEnum.html)
+/// or [`ShiftEnum`](derive.ShiftEnum.html).
 ///
 /// ```
-/// # #[derive(Clone, Debug)]
-/// # enum Direction {
+/// # pub enum Direction {
 /// #     Up,
 /// #     Left,
 /// #     Down,
 /// #     Right,
 /// # }
-/// struct DirectionIterator(Option<Direction>);
+/// pub struct DirectionIterator(usize, usize);
+///
+/// impl DirectionIterator {
+///     pub fn new() -> Self {
+///         Self(0, 4)
+///     }
+///
+///     pub fn remaining(&self) -> usize {
+///         self.1
+///     }
+/// }
+///
+/// impl Default for DirectionIterator {
+///     fn default() -> Self {
+///         Self::new()
+///     }
+/// }
 ///
 /// impl Iterator for DirectionIterator {
 ///     type Item = Direction;
 ///     fn next(&mut self) -> Option<Self::Item> {
-///         let ret = self.0.clone();
-///         self.0 = match self.0 {
-///             Some(Direction::Up) => Some(Direction::Left),
-///             Some(Direction::Left) => Some(Direction::Down),
-///             Some(Direction::Down) => Some(Direction::Right),
-///             Some(Direction::Right) => None,
-///             None => None,
+///         if self.1 == 0 {
+///             return None;
+///         }
+///         let ret = match self.0 {
+///             0 => Some(Direction::Up),
+///             1 => Some(Direction::Left),
+///             2 => Some(Direction::Down),
+///             3 => Some(Direction::Right),
+///             _ => None,
 ///         };
+///         self.0 = if self.0 + 1 == 4 { 0 } else { self.0 + 1 };
+///         self.1 -= 1;
 ///         ret
 ///     }
+///
+///     fn size_hint(&self) -> (usize, Option<usize>) {
+///         (self.remaining(), Some(self.remaining()))
+///     }
+/// }
+///
+/// impl ExactSizeIterator for DirectionIterator {}
+///
+/// impl Direction {
+///     pub fn iter(&self) -> DirectionIterator {
+///         match *self {
+///             Direction::Up => DirectionIterator(0, 4),
+///             Direction::Left => DirectionIterator(1, 3),
+///             Direction::Down => DirectionIterator(2, 2),
+///             Direction::Right => DirectionIterator(3, 1),
+///         }
+///     }
+///
+///     pub fn iter_refs(&self) -> std::slice::Iter<'static, Direction> {
+///         static VARIANTS: [Direction; 4] = [
+///             Direction::Up,
+///             Direction::Left,
+///             Direction::Down,
+///             Direction::Right,
+///         ];
+///         VARIANTS[self.iter().0..].iter()
+///     }
+///
+///     pub fn iter_indexed(&self) -> impl Iterator<Item = (usize, Direction)> {
+///         let mut iter = self.iter();
+///         std::iter::from_fn(move || {
+///             let index = iter.0;
+///             iter.next().map(|variant| (index, variant))
+///         })
+///     }
+/// }
+///
+/// impl<'a> IntoIterator for &'a Direction {
+///     type Item = Direction;
+///     type IntoIter = DirectionIterator;
+///     fn into_iter(self) -> DirectionIterator {
+///         self.iter()
+///     }
 /// }
 /// ```
-#[proc_macro_derive(IterEnum)]
+#[proc_macro_derive(IterEnum, attributes(rotate, iter))]
 pub fn iter_enum(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
-    let name = input.ident;
-
-    let variants = if let Data::Enum(data) = &input.data {
-        data.variants.iter().collect::<Vec<_>>()
-    } else {
-        panic!("derive(RotateEnum) must be applied to an enum");
+    let tools = Tools {
+        iter: true,
+        ..Tools::default()
     };
+    derive(input, tools, "derive(IterEnum)")
+}
 
-    let first_variant = variants
-        .first()
-        .expect("derive(IterEnum) expects at least one variant in enum");
-
-    let nexts = variants
-        .iter()
-        .skip(1)
-        .map(|v| quote! { Some(#name::#v) })
-        .chain(Some(quote! { None }))
-        .collect::<Vec<_>>();
-
-    let iterator_name = syn::Ident::new(&(name.to_string() + "Iterator"), name.span());
-
-    let tokens = quote! {
+/// This derive macro will implement any combination of the methods generated by the other
+/// derives in this crate, selected with an `#[enum_tools(...)]` attribute.
+///
+/// This is handy when you annotate many enums with the same set of features, since you only
+/// need to list them in one place.
+///
+/// For code examples, see [module-level docs](index.html).
+///
+/// # Tools
+///
+/// * `rotate` - `next()` and `prev()` as generated by [`RotateEnum`](derive.RotateEnum.html).
+/// * `shift` - `next()` and `prev()` as generated by [`ShiftEnum`](derive.ShiftEnum.html),
+///   including its `#[shift(...)]` options.
+/// * `bounce` - `bounce()` and `bounce_iter()` as generated by [`BounceEnum`](derive.BounceEnum.html).
+/// * `iter` - `iter()` and the iterator as generated by [`IterEnum`](derive.IterEnum.html),
+///   including its `#[iter(...)]` options.
+/// * `count` - `COUNT`, `index()` and `from_index()`. Implied by `rotate`, `shift`, `bounce` and `names`.
+/// * `names` - `VARIANT_NAMES`, an array of the variant names in the order of their indices,
+///   and `name()`, which returns the name of the variant as a `&'static str`.
+///   `names()` iterates over the names in the same order, e.g. to fill a dropdown
+///   together with the iterator of the first variant.
+///   It also implements `FromStr`, which finds the variant by binary search on the sorted names,
+///   so it stays fast for large enums. Parsing fails with `"YourEnum"ParseError`, whose `input()` is the
+///   rejected string and whose `suggestion` is the closest variant name if the string is only a few typos
+///   or a different case away from it, e.g. `unknown variant "leftt" of Direction, did you mean "Left"?`.
+///   Being `no_std`, the error keeps only the first 32 bytes of the input.
+///   After renaming a variant, `#[name(renamed_from = "OldName")]` on it lets `FromStr` accept the old name too,
+///   so that existing config files keep working. It can be repeated for several old names.
+///   `from_prefix()` is more lenient, like command line tools that accept abbreviations:
+///   it finds the variant whose name starts with the given string, ignoring ASCII case.
+///   A name that matches in full wins over longer names, and `None` is returned if the prefix is ambiguous.
+///   Old names given with `renamed_from` are not considered.
+///
+/// # Variant attributes
+///
+/// These are generated whenever the variants are annotated, regardless of the tools.
+/// If one variant has an attribute, all of them need to have it.
+///
+/// * `#[value(...)]` - a `f64` constant for the variant. Generates `value()`, which returns it,
+///   and `from_value()`, which finds the variant with the given value.
+///
+/// ```
+/// # use rotate_enum::EnumTools;
+/// #[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+/// #[enum_tools(rotate)]
+/// enum Direction {
+///     #[value(90.0)]
+///     Up,
+///     #[value(180.0)]
+///     Left,
+///     #[value(270.0)]
+///     Down,
+///     #[value(0.0)]
+///     Right,
+/// }
+///
+/// assert_eq!(Direction::Left.value(), 180.0);
+/// assert_eq!(Direction::from_value(0.0), Some(Direction::Right));
+/// assert_eq!(Direction::from_value(45.0), None);
+/// ```
+///
+/// * `#[key(...)]` - a `char` for the variant, e.g. a key to press to select it. Generates `to_char()`,
+///   which returns it, and `TryFrom<char>`, which finds the variant with the given key or returns the key back.
+///
+/// ```
+/// # use rotate_enum::EnumTools;
+/// use std::convert::TryFrom;
+///
+/// #[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+/// #[enum_tools(rotate)]
+/// enum Direction {
+///     #[key('w')]
+///     Up,
+///     #[key('a')]
+///     Left,
+///     #[key('s')]
+///     Down,
+///     #[key('d')]
+///     Right,
+/// }
+///
+/// assert_eq!(Direction::Left.to_char(), 'a');
+/// assert_eq!(Direction::try_from('d'), Ok(Direction::Right));
+/// assert_eq!(Direction::try_from('x'), Err('x'));
+/// ```
+///
+/// * `#[pair("Variant")]` - the variant that this one is the counterpart of. The pairs must be symmetric,
+///   and a variant can be paired with itself. Generates `flipped()`, which returns the counterpart.
+///
+/// ```
+/// # use rotate_enum::EnumTools;
+/// #[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+/// #[enum_tools(rotate)]
+/// enum Side {
+///     #[pair("Right")]
+///     Left,
+///     #[pair("Center")]
+///     Center,
+///     #[pair("Left")]
+///     Right,
+/// }
+///
+/// assert_eq!(Side::Left.flipped(), Side::Right);
+/// assert_eq!(Side::Center.flipped(), Side::Center);
+/// ```
+///
+/// * `#[category(Enum::Variant)]` - a variant of another enum that this one belongs to.
+///   Generates `category()`, which returns it. All the categories must be variants of the same enum.
+///
+/// ```
+/// # use rotate_enum::EnumTools;
+/// #[derive(PartialEq, Debug)]
+/// enum Axis {
+///     Horizontal,
+///     Vertical,
+/// }
+///
+/// #[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+/// #[enum_tools(rotate)]
+/// enum Direction {
+///     #[category(Axis::Vertical)]
+///     Up,
+///     #[category(Axis::Horizontal)]
+///     Left,
+///     #[category(Axis::Vertical)]
+///     Down,
+///     #[category(Axis::Horizontal)]
+///     Right,
+/// }
+///
+/// assert_eq!(Direction::Up.category(), Axis::Vertical);
+/// assert_eq!(Direction::Left.next().category(), Axis::Vertical);
+/// ```
+///
+/// * `#[weight(...)]` - a `u32` weight of the variant, e.g. how common an item is in a loot table.
+///   Generates `weight()`, which returns it. With `#[rotate(rand)]`, it also generates `random_weighted(rng)`,
+///   which picks a variant with a probability proportional to its weight.
+///
+/// ```
+/// # use rotate_enum::EnumTools;
+/// #[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+/// #[enum_tools(rotate)]
+/// enum Loot {
+///     #[weight(10)]
+///     Coin,
+///     #[weight(3)]
+///     Potion,
+///     #[weight(1)]
+///     Sword,
+/// }
+///
+/// assert_eq!(Loot::Potion.weight(), 3);
+/// ```
+///
+/// * `#[label = "..."]` - text to show for the variant in a UI. Generates `label()`, which returns it.
+///   Unlike the other attributes, it can be left out on some variants, which are labeled with their names.
+///
+/// ```
+/// # use rotate_enum::EnumTools;
+/// #[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+/// #[enum_tools(rotate)]
+/// enum Direction {
+///     #[label = "Move up"]
+///     Up,
+///     #[label = "Move down"]
+///     Down,
+///     Wait,
+/// }
+///
+/// assert_eq!(Direction::Up.label(), "Move up");
+/// assert_eq!(Direction::Down.next().label(), "Wait");
+/// ```
+///
+/// # Subsets
+///
+/// `#[subset(Name: Variant, ...)]` on the enum declares another enum with the listed variants, in that order,
+/// and the same visibility. It derives `Clone`, `Copy`, `Debug`, `PartialEq`, `Eq` and `Hash`,
+/// and gets the same tools as the enum, except that it is always rotatable unless the enum uses `shift`.
+/// `From` converts the subset into the enum, and `TryFrom` converts back or returns the variant that is not in the subset.
+/// The attribute can be repeated to declare several subsets.
+///
+/// ```
+/// # use rotate_enum::EnumTools;
+/// use std::convert::TryFrom;
+///
+/// #[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+/// #[enum_tools(rotate)]
+/// #[subset(Cardinal: Up, Left, Down, Right)]
+/// enum Direction {
+///     Up,
+///     UpLeft,
+///     Left,
+///     DownLeft,
+///     Down,
+///     DownRight,
+///     Right,
+///     UpRight,
+/// }
+///
+/// assert_eq!(Cardinal::Right.next(), Cardinal::Up);
+/// assert_eq!(Direction::from(Cardinal::Left), Direction::Left);
+/// assert_eq!(Cardinal::try_from(Direction::Down), Ok(Cardinal::Down));
+/// assert_eq!(Cardinal::try_from(Direction::UpLeft), Err(Direction::UpLeft));
+/// ```
+///
+/// # Kinds
+///
+/// `#[kind(Name)]` on the enum declares another enum with the same variants, but without their data,
+/// and `kind()` that returns the variant of it. It derives the same traits as a subset and gets the tools
+/// instead of the enum if the enum carries data, so that the kind of the value can be rotated or iterated.
+/// The options on the enum and the `#[rotate(...)]` and `#[name(...)]` attributes on the variants apply to it too.
+///
+/// ```
+/// # use rotate_enum::EnumTools;
+/// #[derive(EnumTools)]
+/// #[enum_tools(rotate, iter)]
+/// #[kind(ShapeKind)]
+/// enum Shape {
+///     Circle { radius: f64 },
+///     Square(f64),
+///     Point,
+/// }
+///
+/// let shape = Shape::Circle { radius: 1.0 };
+/// assert_eq!(shape.kind(), ShapeKind::Circle);
+/// assert_eq!(Shape::Point.kind().next(), ShapeKind::Circle);
+/// assert_eq!(ShapeKind::Circle.iter().count(), 3);
+/// ```
+///
+/// # Requirements
+///
+/// * It must be applied to an enum. Structs are not supported or won't make sense.
+/// * Enums with any associated data are not supported, except with `#[kind(...)]` or `#[rotate(skip_data)]`.
+/// * The variants must fit in an integer `#[repr(...)]`, see [Indexing](index.html#indexing).
+/// * At least one tool or variant attribute must be given, and `rotate` and `shift` cannot be given at the same time.
+#[proc_macro_derive(
+    EnumTools,
+    attributes(
+        enum_tools, rotate, iter, shift, subset, kind, name, value, key, pair, category, weight,
+        label
+    )
+)]
+pub fn enum_tools(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    Tools::from_attrs(&input.attrs)
+        .and_then(|tools| tools.expand(&input, "derive(EnumTools)"))
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
 
-        struct #iterator_name(Option<#name>);
+/// This macro defines the enum given to it along with rotation, iteration, `COUNT` and names,
+/// i.e. the same as `#[derive(EnumTools)]` with `#[enum_tools(rotate, iter, count, names)]`.
+///
+/// It is a terser alternative to listing derives when you define many enums at once,
+/// e.g. in generated code or tests.
+///
+/// ```
+/// rotate_enum::rotate_enum! {
+///     #[derive(PartialEq, Clone, Copy, Debug)]
+///     pub enum Direction {
+///         Up,
+///         Left,
+///         Down,
+///         Right,
+///     }
+/// }
+///
+/// assert_eq!(Direction::Right.next(), Direction::Up);
+/// assert_eq!(Direction::Left.iter().count(), 3);
+/// assert_eq!(Direction::COUNT, 4);
+/// assert_eq!(Direction::Down.name(), "Down");
+/// ```
+///
+/// Since rotation and shifting both define `next()` and `prev()`, only rotation is generated by default.
+/// You can choose other tools with the same `#[enum_tools(...)]` attribute as [`EnumTools`](derive.EnumTools.html).
+///
+/// ```
+/// rotate_enum::rotate_enum! {
+///     #[derive(PartialEq, Clone, Copy, Debug)]
+///     #[enum_tools(shift, count)]
+///     enum Direction {
+///         Up,
+///         Left,
+///         Down,
+///         Right,
+///     }
+/// }
+///
+/// assert_eq!(Direction::Right.next(), None);
+/// ```
+#[proc_macro]
+pub fn rotate_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let tools = Tools::from_attrs_or_defaults(&input.attrs);
+    define(input, tools, "rotate_enum!")
+}
 
-        impl #iterator_name {
-            fn new() -> Self {
-                Self(Some(#name::#first_variant))
-            }
-        }
+/// This attribute macro generates the same methods as [`rotate_enum!`](macro.rotate_enum.html),
+/// but is applied to an ordinary enum definition like a derive.
+///
+/// The tools to generate can be given as arguments, e.g. `#[enumeration(shift, iter)]`,
+/// with the same names as [`EnumTools`](derive.EnumTools.html).
+/// Without arguments, it generates rotation, iteration, `COUNT` and names.
+///
+/// Unlike derive macros, an attribute macro can rewrite the enum, so it removes
+/// the per-variant metadata attributes this crate understands after reading them.
+/// This means they cannot conflict with attributes of the same name from other crates
+/// that are applied later.
+///
+/// ```
+/// #[rotate_enum::enumeration(rotate, count)]
+/// #[derive(PartialEq, Clone, Copy, Debug)]
+/// enum Direction {
+///     Up,
+///     Left,
+///     Down,
+///     Right,
+/// }
+///
+/// assert_eq!(Direction::Right.next(), Direction::Up);
+/// assert_eq!(Direction::COUNT, 4);
+/// ```
+#[proc_macro_attribute]
+pub fn enumeration(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as AttributeArgs);
+    let input = parse_macro_input!(input as DeriveInput);
+    let tools = if args.is_empty() {
+        Tools::from_attrs_or_defaults(&input.attrs)
+    } else {
+        Tools::from_nested(&args)
+    };
+    define(input, tools, "#[enumeration]")
+}
 
-        impl Iterator for #iterator_name {
-            type Item = #name;
-            fn next(&mut self) -> Option<Self::Item> {
-                let ret = self.0.clone();
-                self.0 = match self.0 {
-                    #(Some(#name::#variants) => #nexts, )*
-                    None => None,
-                };
-                ret
-            }
-        }
+/// This macro repeats a body once for each variant of an enum, at compile time instead of in a loop.
+///
+/// The variant is bound to the given name as a `const`, so the body can use it in
+/// constant expressions and declare items per variant. The body is a list of statements,
+/// which may also be a single expression or block.
+/// It works for enums with any of the macros that generate `COUNT`, i.e. [`RotateEnum`](derive.RotateEnum.html),
+/// [`ShiftEnum`](derive.ShiftEnum.html) or [`EnumTools`](derive.EnumTools.html) with `count`.
+///
+/// The enum is named by its path in the same crate, like `crate::compass::Direction`.
+/// The macro forwards to a helper defined by the derive next to the enum, which is found at the same path.
+/// A `use` of the enum does not bring the helper along, so a bare name only works in the module of the enum
+/// (after its declaration) and its children. Enums from other crates are not supported.
+///
+/// ```
+/// use rotate_enum::{for_each_variant, RotateEnum};
+///
+/// #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+/// enum Direction {
+///     Up,
+///     Left,
+///     Down,
+///     Right,
+/// }
+///
+/// let mut angles = vec![];
+/// for_each_variant!(Direction, |direction| {
+///     const ANGLE: u16 = 90 * direction as u16;
+///     angles.push((direction, ANGLE));
+/// });
+/// assert_eq!(angles[1], (Direction::Left, 90));
+/// assert_eq!(angles.len(), 4);
+/// ```
+#[proc_macro]
+pub fn for_each_variant(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as for_each::ForEachVariant);
+    for_each::expand(input).into()
+}
 
-        impl #name {
-            fn iter(&self) -> #iterator_name {
-                #iterator_name(Some(self.clone()))
-            }
-        }
-    };
+/// This macro evaluates to an array of consecutive variants of an enum, starting with the given one
+/// and wrapping around after the last variant like `wrapping_add()`, at compile time.
+///
+/// The array is a constant expression, so fixed patterns of variants can be declared as `const`s or `static`s,
+/// where `next()` cannot be called.
+/// It works for enums with any of the macros that generate `next()` and `prev()` by rotation,
+/// i.e. [`RotateEnum`](derive.RotateEnum.html) or [`EnumTools`](derive.EnumTools.html) with `rotate`.
+/// Like [`for_each_variant!`](macro.for_each_variant.html), the enum is named by its path
+/// in the same crate, followed by the first variant.
+///
+/// ```
+/// use rotate_enum::{rotate_sequence, RotateEnum};
+///
+/// #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+/// enum Direction {
+///     Up,
+///     Left,
+///     Down,
+///     Right,
+/// }
+///
+/// const TURNS: [Direction; 3] = rotate_sequence!(Direction::Left, 3);
+/// assert_eq!(TURNS, [Direction::Left, Direction::Down, Direction::Right]);
+/// assert_eq!(rotate_sequence!(Direction::Right, 2), [Direction::Right, Direction::Up]);
+/// ```
+#[proc_macro]
+pub fn rotate_sequence(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as sequence::RotateSequence);
+    sequence::expand(input).into()
+}
 
-    tokens.into()
+/// This macro builds the table of [`#[rotate(table)]`](index.html#options) from a value for each variant
+/// by name, checking at compile time that every variant is given exactly once.
+///
+/// Unlike the array given to `new()`, the values don't depend on the order of the variants,
+/// and forgetting a variant after adding it to the enum is an error instead of a wrong value.
+/// Each value is evaluated once, in the order they are written, and may be of a type that is not `Copy`.
+/// The table is a constant expression if the values are.
+/// Like [`for_each_variant!`](macro.for_each_variant.html), the enum is named by its path in the same crate.
+///
+/// ```
+/// use rotate_enum::{enum_table, RotateEnum};
+///
+/// #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+/// #[rotate(table)]
+/// enum Direction {
+///     Up,
+///     Left,
+///     Down,
+///     Right,
+/// }
+///
+/// const ANGLES: DirectionTable<f32> = enum_table!(Direction => f32 {
+///     Up: 0.0,
+///     Right: 270.0,
+///     Left: 90.0,
+///     Down: 180.0,
+/// });
+/// assert_eq!(ANGLES[Direction::Right], 270.0);
+/// ```
+///
+/// A missing variant fails to compile:
+///
+/// ```compile_fail
+/// # use rotate_enum::{enum_table, RotateEnum};
+/// # #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+/// # #[rotate(table)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// const ANGLES: DirectionTable<f32> = enum_table!(Direction => f32 {
+///     Up: 0.0,
+///     Left: 90.0,
+///     Down: 180.0,
+/// });
+/// ```
+///
+/// So does an enum without `#[rotate(table)]`, with an error that asks for it:
+///
+/// ```compile_fail
+/// # use rotate_enum::{enum_table, RotateEnum};
+/// # #[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+/// # enum Direction {
+/// #     Up,
+/// #     Left,
+/// #     Down,
+/// #     Right,
+/// # }
+/// // error: enum_table! needs #[rotate(table)] on Direction
+/// let angles = enum_table!(Direction => f32 {
+///     Up: 0.0,
+///     Left: 90.0,
+///     Down: 180.0,
+///     Right: 270.0,
+/// });
+/// ```
+#[proc_macro]
+pub fn enum_table(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as table::EnumTable);
+    table::expand(input).into()
 }
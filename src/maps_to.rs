@@ -0,0 +1,53 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+
+/// Generates `From` conversions in both directions between the enum and each enum given with
+/// `#[rotate(maps_to = "OtherEnum")]`, which pair up the variants with the same index.
+///
+/// The other enum is not visible to the macro, so it has to have `COUNT`, `index()` and `from_index()`
+/// from one of the macros of this crate, and the number of variants is checked by a constant assertion.
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<Option<TokenStream>> {
+    if info.options.maps_to.is_empty() {
+        return Ok(None);
+    }
+    let name = info.name;
+    let conversions = info
+        .options
+        .maps_to
+        .iter()
+        .map(|lit| {
+            let other = lit.parse::<Path>()?;
+            let message = format!(
+                "{} and {} must have the same number of variants to map between them",
+                name,
+                lit.value()
+            );
+            // Panicking in a constant needs Rust 1.57, so the older way only fails with an array length mismatch.
+            let check = if cfg!(feature = "msrv") {
+                quote! { const _: [(); 0] = [(); (#name::COUNT != #other::COUNT) as usize]; }
+            } else {
+                quote! { const _: () = ::core::assert!(#name::COUNT == #other::COUNT, #message); }
+            };
+            Ok(quote! {
+                #check
+
+                #[allow(deprecated)]
+                impl ::core::convert::From<#name> for #other {
+                    fn from(value: #name) -> Self {
+                        Self::from_index(value.index()).unwrap()
+                    }
+                }
+
+                #[allow(deprecated)]
+                impl ::core::convert::From<#other> for #name {
+                    fn from(value: #other) -> Self {
+                        Self::from_index(value.index()).unwrap()
+                    }
+                }
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(Some(quote! { #(#conversions)* }))
+}
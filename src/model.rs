@@ -0,0 +1,653 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    ext::IdentExt, parse::Parse, Attribute, Data, DataEnum, DeriveInput, Expr, ExprLit, ExprUnary,
+    Fields, Ident, Lit, LitInt, LitStr, Meta, MetaNameValue, NestedMeta, Path, UnOp, Variant,
+    Visibility,
+};
+
+/// The parts of the annotated enum that the generators need.
+pub(crate) struct EnumInfo<'a> {
+    pub name: &'a Ident,
+    pub vis: &'a Visibility,
+    /// Attributes of the enum itself.
+    pub attrs: &'a [Attribute],
+    pub options: Options,
+    /// Variants in index order, which is the declaration order unless they are numbered
+    /// with `#[rotate(ordinal = N)]`.
+    pub variants: Vec<&'a Ident>,
+    /// Attributes of each variant, in the same order as `variants`.
+    pub variant_attrs: Vec<&'a [Attribute]>,
+    /// Whether each variant is marked with `#[rotate(self_loop)]`, in the same order as `variants`.
+    pub self_loops: Vec<bool>,
+    /// Fields of each variant, which are all empty unless the enum carries data.
+    pub fields: Vec<&'a Fields>,
+    /// Whether any variant declares its discriminant, like `Up = 1`.
+    pub has_discriminants: bool,
+    /// Variants with fields that are left out of `variants` by `#[rotate(skip_data)]`, in declaration order.
+    pub skipped: Vec<Skipped<'a>>,
+}
+
+/// A variant with fields that `#[rotate(skip_data)]` leaves out of the variants.
+/// It has no index of its own, so it stands in for the unit variants declared around it.
+pub(crate) struct Skipped<'a> {
+    pub ident: &'a Ident,
+    pub attrs: &'a [Attribute],
+    /// The index of the unit variant declared after it, wrapping around to the first one,
+    /// which it shares `index()` and the other methods written in terms of it with.
+    pub index: usize,
+    /// The index of the unit variant declared before it, wrapping around to the last one.
+    pub prev: usize,
+    /// The indices of the unit variants declared right after and before it, without wrapping around.
+    pub after: Option<usize>,
+    pub before: Option<usize>,
+}
+
+/// Whether `#[kind(...)]` or `#[iter(flatten)]` is given, which generate the code for the variants with fields.
+fn covers_data(attrs: &[Attribute]) -> syn::Result<bool> {
+    let flatten = options(attrs, "iter")?
+        .iter()
+        .any(|option| matches!(option, Meta::Path(path) if path.is_ident("flatten")));
+    Ok(flatten || attrs.iter().any(|attr| attr.path.is_ident("kind")))
+}
+
+impl<'a> EnumInfo<'a> {
+    pub fn new(input: &'a DeriveInput, macro_name: &str) -> syn::Result<Self> {
+        let data = if let Data::Enum(data) = &input.data {
+            data
+        } else {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                format!("{} must be applied to an enum", macro_name),
+            ));
+        };
+
+        check_repr(input, data)?;
+
+        let options = Options::new(&input.attrs)?;
+        if !options.skip_data && !covers_data(&input.attrs)? {
+            if let Some(variant) = data.variants.iter().find(|v| !v.fields.is_empty()) {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    format!(
+                        "{} cannot generate methods for {}, which carries data; \
+                         use #[kind(...)] with EnumTools for a fieldless copy of the enum, \
+                         or #[rotate(skip_data)] to leave the variants with fields out",
+                        macro_name,
+                        variant.ident.unraw()
+                    ),
+                ));
+            }
+        }
+        let mut variants = data
+            .variants
+            .iter()
+            .filter(|v| !options.skip_data || v.fields.is_empty())
+            .collect::<Vec<_>>();
+        if let Some(ordinals) = ordinals(&variants)? {
+            let mut sorted = variants.iter().copied().zip(ordinals).collect::<Vec<_>>();
+            sorted.sort_by_key(|(_, ordinal)| *ordinal);
+            variants = sorted.into_iter().map(|(variant, _)| variant).collect();
+        }
+
+        let skipped = skipped_variants(data, &variants)?;
+
+        Ok(Self {
+            name: &input.ident,
+            vis: &input.vis,
+            attrs: &input.attrs,
+            options,
+            variants: variants.iter().map(|v| &v.ident).collect(),
+            variant_attrs: variants.iter().map(|v| &v.attrs[..]).collect(),
+            self_loops: variants
+                .iter()
+                .map(|v| VariantOptions::new(v).map(|options| options.self_loop))
+                .collect::<syn::Result<_>>()?,
+            fields: variants.iter().map(|v| &v.fields).collect(),
+            has_discriminants: variants.iter().any(|v| v.discriminant.is_some()),
+            skipped,
+        })
+    }
+
+    /// Whether any variant has fields.
+    pub fn carries_data(&self) -> bool {
+        self.fields.iter().any(|fields| !fields.is_empty())
+    }
+
+    /// The pattern of each variant in index order, as `prefix::Variant`, which also matches
+    /// the variants skipped by `#[rotate(skip_data)]` that share its index.
+    pub fn variant_patterns(&self, prefix: &TokenStream) -> Vec<TokenStream> {
+        self.variants
+            .iter()
+            .enumerate()
+            .map(|(index, variant)| {
+                let skipped = self
+                    .skipped
+                    .iter()
+                    .filter(|skipped| skipped.index == index)
+                    .map(|skipped| skipped.ident);
+                quote! { #prefix::#variant #(| #prefix::#skipped { .. })* }
+            })
+            .collect()
+    }
+
+    /// The identifiers of the variants skipped by `#[rotate(skip_data)]`.
+    pub fn skipped_idents(&self) -> Vec<&'a Ident> {
+        self.skipped.iter().map(|skipped| skipped.ident).collect()
+    }
+
+    /// Names of the variants skipped by `#[rotate(skip_data)]`, like `variant_names()`.
+    pub fn skipped_names(&self) -> Vec<String> {
+        self.skipped
+            .iter()
+            .map(|skipped| skipped.ident.unraw().to_string())
+            .collect()
+    }
+
+    /// Variant indices, as literals to be interpolated next to `variants`.
+    pub fn indices(&self) -> Vec<usize> {
+        (0..self.variants.len()).collect()
+    }
+
+    /// Variant names as written by the user, without the `r#` prefix of raw identifiers.
+    pub fn variant_names(&self) -> Vec<String> {
+        self.variants
+            .iter()
+            .map(|v| v.unraw().to_string())
+            .collect()
+    }
+
+    /// Creates an identifier for a generated item, such as `DirectionIterator` for `Direction`.
+    pub fn derived_ident(&self, suffix: &str) -> Ident {
+        Ident::new(
+            &format!("{}{}", self.name.unraw(), suffix),
+            self.name.span(),
+        )
+    }
+
+    /// The path of rotate-enum-runtime in the generated code, which can be changed with `#[rotate(crate = "...")]`.
+    pub fn runtime_crate(&self) -> TokenStream {
+        match &self.options.krate {
+            Some(path) => quote! { #path },
+            None => quote! { ::rotate_enum_runtime },
+        }
+    }
+
+    /// The visibility of a generated type, which is that of the enum, but written so that
+    /// it means the same inside the hidden module of `#[rotate(hidden_module)]`.
+    pub fn type_vis(&self) -> TokenStream {
+        if !self.options.hidden_module {
+            let vis = self.vis;
+            return quote! { #vis };
+        }
+        match self.vis {
+            Visibility::Public(_) => quote! { pub },
+            Visibility::Crate(_) => quote! { pub(crate) },
+            Visibility::Inherited => quote! { pub(super) },
+            Visibility::Restricted(restricted) => {
+                let path = &restricted.path;
+                match path.segments.first() {
+                    Some(first) if path.leading_colon.is_none() && first.ident == "self" => {
+                        let rest = path.segments.iter().skip(1);
+                        quote! { pub(in super #(::#rest)*) }
+                    }
+                    Some(first) if path.leading_colon.is_none() && first.ident == "super" => {
+                        quote! { pub(in super::#path) }
+                    }
+                    _ => quote! { pub(in #path) },
+                }
+            }
+        }
+    }
+
+    /// Wraps a generated type and its impls in a hidden module if `#[rotate(hidden_module)]` is given,
+    /// and re-exports the type with the visibility of the enum.
+    /// The re-export is a glob import, which the items of the user shadow instead of colliding with.
+    pub fn namespace(&self, ty: &Ident, items: TokenStream) -> TokenStream {
+        if !self.options.hidden_module {
+            return items;
+        }
+        let vis = self.vis;
+        let module = Ident::new(
+            &format!("__rotate_enum_{}", snake_case(&ty.unraw().to_string())),
+            ty.span(),
+        );
+        quote! {
+            #[doc(hidden)]
+            mod #module {
+                use super::*;
+                #items
+            }
+            #vis use self::#module::*;
+        }
+    }
+
+    /// Finds the index of the variant named by a string literal in an attribute.
+    pub fn find_variant(&self, lit: &LitStr) -> syn::Result<usize> {
+        let name = lit.value();
+        self.variants
+            .iter()
+            .position(|v| v.unraw() == name)
+            .ok_or_else(|| {
+                syn::Error::new_spanned(lit, format!("{} has no variant {}", self.name, name))
+            })
+    }
+
+    /// Parses the arguments of `#[attr_name(...)]` on each variant.
+    ///
+    /// Returns `None` if no variant has the attribute, and an error if only some of them do.
+    pub fn variant_args<T: Parse>(&self, attr_name: &str) -> syn::Result<Option<Vec<T>>> {
+        let args = self
+            .variant_attrs
+            .iter()
+            .map(|attrs| {
+                attrs
+                    .iter()
+                    .find(|attr| attr.path.is_ident(attr_name))
+                    .map(|attr| attr.parse_args::<T>())
+                    .transpose()
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        if args.iter().all(Option::is_none) {
+            return Ok(None);
+        }
+
+        args.into_iter()
+            .zip(&self.variants)
+            .map(|(arg, variant)| {
+                arg.ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        variant,
+                        format!(
+                            "missing #[{}(...)] on this variant, since other variants have one",
+                            attr_name
+                        ),
+                    )
+                })
+            })
+            .collect::<syn::Result<_>>()
+            .map(Some)
+    }
+}
+
+/// Collects the items of all `#[attr_name(...)]` attributes, e.g. `start = "Down"` in `#[iter(start = "Down")]`.
+pub(crate) fn options(attrs: &[Attribute], attr_name: &str) -> syn::Result<Vec<Meta>> {
+    let mut options = vec![];
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident(attr_name)) {
+        match attr.parse_meta()? {
+            Meta::List(list) => {
+                for nested in list.nested {
+                    match nested {
+                        syn::NestedMeta::Meta(meta) => options.push(meta),
+                        syn::NestedMeta::Lit(lit) => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                format!("expected an option like #[{}(name = value)]", attr_name),
+                            ))
+                        }
+                    }
+                }
+            }
+            meta => {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    format!("expected a list of options like #[{}(...)]", attr_name),
+                ))
+            }
+        }
+    }
+    Ok(options)
+}
+
+/// Checks that the discriminants of the variants fit in the integer type given with `#[repr(...)]`,
+/// so that the user gets an error that explains the overflow in terms of the enum.
+///
+/// The discriminants are counted like the compiler does, from the explicit ones given as integer
+/// literals. The check stops at a discriminant given by any other expression, which is left to the
+/// compiler, and so are `usize` and `isize`, whose range depends on the target.
+fn check_repr(input: &DeriveInput, data: &DataEnum) -> syn::Result<()> {
+    for attr in input.attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        let list = if let Ok(Meta::List(list)) = attr.parse_meta() {
+            list
+        } else {
+            continue;
+        };
+        for nested in &list.nested {
+            let ty = match nested {
+                NestedMeta::Meta(Meta::Path(path)) => path.get_ident(),
+                _ => None,
+            };
+            let (min, max) = match ty.map(Ident::to_string).as_deref() {
+                Some("u8") => (0, u8::MAX as i128),
+                Some("u16") => (0, u16::MAX as i128),
+                Some("u32") => (0, u32::MAX as i128),
+                Some("u64") => (0, u64::MAX as i128),
+                Some("u128") => (0, i128::MAX),
+                Some("i8") => (i8::MIN as i128, i8::MAX as i128),
+                Some("i16") => (i16::MIN as i128, i16::MAX as i128),
+                Some("i32") => (i32::MIN as i128, i32::MAX as i128),
+                Some("i64") => (i64::MIN as i128, i64::MAX as i128),
+                Some("i128") => (i128::MIN, i128::MAX),
+                _ => continue,
+            };
+            let mut discriminant = Some(0i128);
+            for variant in &data.variants {
+                if let Some((_, expr)) = &variant.discriminant {
+                    discriminant = literal_discriminant(expr);
+                }
+                let value = match discriminant {
+                    Some(value) => value,
+                    None => break,
+                };
+                if value < min || max < value {
+                    return Err(syn::Error::new_spanned(
+                        nested,
+                        format!(
+                            "{}::{} has the discriminant {}, but #[repr({})] only holds {} to {}",
+                            input.ident,
+                            variant.ident,
+                            value,
+                            quote::ToTokens::to_token_stream(nested),
+                            min,
+                            max
+                        ),
+                    ));
+                }
+                discriminant = value.checked_add(1);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Evaluates a discriminant given as an integer literal, which may be negated.
+fn literal_discriminant(expr: &Expr) -> Option<i128> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => literal_discriminant(expr)?.checked_neg(),
+        Expr::Paren(paren) => literal_discriminant(&paren.expr),
+        _ => None,
+    }
+}
+
+/// Converts a type name like `DirectionIterator` to `direction_iterator`.
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut ret = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i != 0 && !ret.ends_with('_') {
+            ret.push('_');
+        }
+        ret.extend(c.to_lowercase());
+    }
+    ret
+}
+
+/// Options given with `#[rotate(...)]` on a variant.
+#[derive(Default)]
+struct VariantOptions {
+    ordinal: Option<LitInt>,
+    self_loop: bool,
+}
+
+impl VariantOptions {
+    fn new(variant: &Variant) -> syn::Result<Self> {
+        let mut ret = Self::default();
+        for option in options(&variant.attrs, "rotate")? {
+            match &option {
+                Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Int(lit),
+                    ..
+                }) if path.is_ident("ordinal") => ret.ordinal = Some(lit.clone()),
+                Meta::Path(path) if path.is_ident("self_loop") => ret.self_loop = true,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        option,
+                        "unknown rotate option on a variant, expected ordinal = N or self_loop",
+                    ))
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+/// Reads `#[rotate(ordinal = N)]` on each variant.
+///
+/// Returns `None` if no variant has an ordinal. Otherwise every variant needs one,
+/// and they must number the variants from 0 without gaps, so that they can be used as indices.
+fn ordinals(variants: &[&Variant]) -> syn::Result<Option<Vec<usize>>> {
+    let mut ordinals = vec![];
+    for variant in variants {
+        ordinals.push(VariantOptions::new(variant)?.ordinal);
+    }
+
+    if ordinals.iter().all(Option::is_none) {
+        return Ok(None);
+    }
+
+    let mut seen = vec![false; variants.len()];
+    ordinals
+        .into_iter()
+        .zip(variants)
+        .map(|(ordinal, variant)| {
+            let lit = ordinal.ok_or_else(|| {
+                syn::Error::new_spanned(
+                    &variant.ident,
+                    "missing #[rotate(ordinal = N)] on this variant, since other variants have one",
+                )
+            })?;
+            let ordinal = lit.base10_parse::<usize>()?;
+            if variants.len() <= ordinal {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    format!(
+                        "ordinal must be less than the number of variants, {}",
+                        variants.len()
+                    ),
+                ));
+            }
+            if std::mem::replace(&mut seen[ordinal], true) {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    format!("ordinal {} is used by more than one variant", ordinal),
+                ));
+            }
+            Ok(ordinal)
+        })
+        .collect::<syn::Result<_>>()
+        .map(Some)
+}
+
+/// Options given to any of the macros with `#[rotate(...)]` on the enum.
+#[derive(Default)]
+pub(crate) struct Options {
+    pub self_test: bool,
+    /// Whether to generate proof harnesses for the Kani model checker.
+    pub kani: bool,
+    pub table: bool,
+    /// The variant to return from `Default::default()`, if requested.
+    /// `Some(None)` selects the first variant.
+    pub default: Option<Option<LitStr>>,
+    /// Whether to print the generated code while compiling.
+    pub debug: bool,
+    /// Whether to declare generated types in a hidden module.
+    pub hidden_module: bool,
+    /// Aliases of `next()` and `prev()`, such as `rotate_cw` and `rotate_ccw`.
+    pub aliases: Option<(Ident, Ident)>,
+    /// Whether to generate `description()` from the doc comments of the variants.
+    pub description: bool,
+    /// The variant that converts from and into `true`, if not the second one.
+    pub true_variant: Option<LitStr>,
+    /// Whether to convert from and into the integer `#[repr(...)]` by the discriminants.
+    pub repr_conversions: bool,
+    /// Whether to export the generated methods as functions with `#[wasm_bindgen]`.
+    pub wasm_bindgen: bool,
+    /// Whether to implement `defmt::Format`.
+    pub defmt: bool,
+    /// Whether to implement `ufmt::uDisplay`.
+    pub ufmt: bool,
+    /// Whether to implement rkyv's `Archive`, `Serialize` and `Deserialize`.
+    pub rkyv: bool,
+    /// Whether to implement `schemars::JsonSchema`.
+    pub schemars: bool,
+    /// Whether to generate the methods that take a random number generator of rand.
+    pub rand: bool,
+    /// Whether to implement num-traits' `Bounded`, `FromPrimitive` and `ToPrimitive`.
+    pub num_traits: bool,
+    /// Whether to implement enum-map's `Enum` and `EnumArray`.
+    pub enum_map: bool,
+    /// Whether to implement ts-rs' `TS`.
+    pub ts_rs: bool,
+    /// Whether to implement the traits of rotate-enum-runtime and convert to and from its `CyclicIndex`.
+    pub runtime: bool,
+    /// Whether to generate `Add`, `IDENTITY` and `inverse()` for the rotations.
+    pub group: bool,
+    /// Whether to leave the variants with fields out, given with `#[rotate(skip_data)]`.
+    pub skip_data: bool,
+    /// Enums to convert from and into by index, given with `#[rotate(maps_to = "OtherEnum")]`.
+    pub maps_to: Vec<LitStr>,
+    /// `cfg(...)` and `cfg_attr(...)` to add to every generated item.
+    pub item_attrs: Vec<Meta>,
+    /// The path of rotate-enum-runtime given with `#[rotate(crate = "...")]`, if it is re-exported.
+    pub krate: Option<Path>,
+}
+
+impl Options {
+    fn new(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut ret = Self::default();
+        for option in options(attrs, "rotate")? {
+            match &option {
+                Meta::Path(path) if path.is_ident("self_test") => ret.self_test = true,
+                Meta::Path(path) if path.is_ident("kani") => ret.kani = true,
+                Meta::Path(path) if path.is_ident("table") => ret.table = true,
+                Meta::Path(path) if path.is_ident("default") => ret.default = Some(None),
+                Meta::Path(path) if path.is_ident("debug") => ret.debug = true,
+                Meta::Path(path) if path.is_ident("hidden_module") => ret.hidden_module = true,
+                Meta::Path(path) if path.is_ident("description") => ret.description = true,
+                Meta::Path(path) if path.is_ident("group") => ret.group = true,
+                Meta::Path(path) if path.is_ident("skip_data") => ret.skip_data = true,
+                Meta::Path(path) if path.is_ident("wasm_bindgen") => ret.wasm_bindgen = true,
+                Meta::Path(path) if path.is_ident("repr_conversions") => {
+                    ret.repr_conversions = true
+                }
+                Meta::Path(path) if path.is_ident("defmt") => ret.defmt = true,
+                Meta::Path(path) if path.is_ident("ufmt") => ret.ufmt = true,
+                Meta::Path(path) if path.is_ident("rkyv") => ret.rkyv = true,
+                Meta::Path(path) if path.is_ident("schemars") => ret.schemars = true,
+                Meta::Path(path) if path.is_ident("rand") => ret.rand = true,
+                Meta::Path(path) if path.is_ident("num_traits") => ret.num_traits = true,
+                Meta::Path(path) if path.is_ident("enum_map") => ret.enum_map = true,
+                Meta::Path(path) if path.is_ident("ts_rs") => ret.ts_rs = true,
+                Meta::Path(path) if path.is_ident("runtime") => ret.runtime = true,
+                Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(lit),
+                    ..
+                }) if path.is_ident("default") => ret.default = Some(Some(lit.clone())),
+                Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(lit),
+                    ..
+                }) if path.is_ident("aliases") => ret.aliases = Some(aliases(lit)?),
+                Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(lit),
+                    ..
+                }) if path.is_ident("true_variant") => ret.true_variant = Some(lit.clone()),
+                Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(lit),
+                    ..
+                }) if path.is_ident("maps_to") => ret.maps_to.push(lit.clone()),
+                Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(lit),
+                    ..
+                }) if path.is_ident("crate") => ret.krate = Some(lit.parse()?),
+                Meta::List(list) if list.path.is_ident("cfg") || list.path.is_ident("cfg_attr") => {
+                    ret.item_attrs.push(option.clone())
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        option,
+                        "unknown rotate option, expected self_test, kani, table, default, debug, hidden_module, aliases, description, true_variant, group, skip_data, wasm_bindgen, repr_conversions, defmt, ufmt, rkyv, schemars, rand, num_traits, enum_map, ts_rs, runtime, maps_to, cfg, cfg_attr or crate",
+                    ))
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+/// Finds the unit variants around each variant that `#[rotate(skip_data)]` leaves out,
+/// given the unit variants in index order.
+fn skipped_variants<'a>(
+    data: &'a DataEnum,
+    variants: &[&'a Variant],
+) -> syn::Result<Vec<Skipped<'a>>> {
+    let index_of = |variant: &Variant| variants.iter().position(|unit| unit.ident == variant.ident);
+    let declared = data.variants.iter().collect::<Vec<_>>();
+    let mut ret = vec![];
+    for (position, variant) in declared.iter().enumerate() {
+        if index_of(variant).is_some() {
+            continue;
+        }
+        let after = declared[position + 1..]
+            .iter()
+            .find_map(|variant| index_of(variant));
+        let before = declared[..position]
+            .iter()
+            .rev()
+            .find_map(|variant| index_of(variant));
+        let first = declared.iter().find_map(|variant| index_of(variant));
+        let last = declared.iter().rev().find_map(|variant| index_of(variant));
+        let (index, prev) = match (after.or(first), before.or(last)) {
+            (Some(index), Some(prev)) => (index, prev),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &variant.ident,
+                    "skip_data needs at least one variant without fields",
+                ))
+            }
+        };
+        ret.push(Skipped {
+            ident: &variant.ident,
+            attrs: &variant.attrs,
+            index,
+            prev,
+            after,
+            before,
+        });
+    }
+    Ok(ret)
+}
+
+/// Parses `"cw_ccw"` in `#[rotate(aliases = "cw_ccw")]` into the aliases `rotate_cw` and `rotate_ccw`.
+fn aliases(lit: &LitStr) -> syn::Result<(Ident, Ident)> {
+    let value = lit.value();
+    let mut suffixes = value.split('_');
+    match (suffixes.next(), suffixes.next(), suffixes.next()) {
+        (Some(next), Some(prev), None) if !next.is_empty() && !prev.is_empty() => {
+            let alias = |suffix: &str| {
+                syn::parse_str::<Ident>(&format!("rotate_{}", suffix))
+                    .map(|ident| Ident::new(&ident.to_string(), lit.span()))
+                    .map_err(|_| {
+                        syn::Error::new_spanned(lit, format!("invalid alias suffix {}", suffix))
+                    })
+            };
+            Ok((alias(next)?, alias(prev)?))
+        }
+        _ => Err(syn::Error::new_spanned(
+            lit,
+            "expected suffixes for next() and prev() separated by an underscore, e.g. \"cw_ccw\"",
+        )),
+    }
+}
@@ -0,0 +1,209 @@
+use crate::model::{options, EnumInfo};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, Attribute, Lit, LitStr, Meta, MetaNameValue};
+
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<TokenStream> {
+    let name = info.name;
+    let vis = info.type_vis();
+    let count = info.variants.len();
+    let names = info.variant_names();
+    let error = info.derived_ident("ParseError");
+    // The variants skipped by `#[rotate(skip_data)]` are named too, though they share the index of another variant.
+    let skipped = info.skipped_idents();
+    let skipped_names = info.skipped_names();
+    let name_of = if skipped.is_empty() {
+        quote! { Self::VARIANT_NAMES[self.index()] }
+    } else {
+        quote! {
+            match *self {
+                #(Self::#skipped { .. } => #skipped_names,)*
+                _ => Self::VARIANT_NAMES[self.index()],
+            }
+        }
+    };
+
+    // FromStr looks names up by binary search, so that parsing stays fast for large enums.
+    let mut sorted = names
+        .iter()
+        .cloned()
+        .zip(info.indices())
+        .collect::<Vec<_>>();
+    for (index, attrs) in info.variant_attrs.iter().enumerate() {
+        for lit in renamed_from(attrs)? {
+            let old_name = lit.value();
+            if sorted.iter().any(|(name, _)| *name == old_name) {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    format!(
+                        "{} is already the name or a former name of a variant",
+                        old_name
+                    ),
+                ));
+            }
+            sorted.push((old_name, index));
+        }
+    }
+    sorted.sort();
+    let sorted_count = sorted.len();
+    let (sorted_names, sorted_indices): (Vec<_>, Vec<_>) = sorted.into_iter().unzip();
+    let enum_name = info.name.unraw().to_string();
+    let max_len = names.iter().map(String::len).max().unwrap_or(0);
+
+    let error_items = quote! {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        #vis struct #error {
+            /// The name of the variant that is closest to the string that failed to parse, if any is close enough.
+            pub suggestion: ::core::option::Option<&'static str>,
+            // The start of the string that failed to parse, kept inline since the error cannot borrow it.
+            input: [u8; 32],
+            input_len: usize,
+            truncated: bool,
+        }
+
+        #[allow(deprecated)]
+        impl #error {
+            pub(crate) fn new(input: &str, suggestion: ::core::option::Option<&'static str>) -> Self {
+                let mut input_len = ::core::cmp::Ord::min(input.len(), 32);
+                while !input.is_char_boundary(input_len) {
+                    input_len -= 1;
+                }
+                let mut buffer = [0; 32];
+                buffer[..input_len].copy_from_slice(&input.as_bytes()[..input_len]);
+                Self {
+                    suggestion,
+                    input: buffer,
+                    input_len,
+                    truncated: input_len < input.len(),
+                }
+            }
+
+            /// The string that failed to parse, cut to its first 32 bytes if it was longer.
+            pub fn input(&self) -> &str {
+                ::core::str::from_utf8(&self.input[..self.input_len]).unwrap_or_default()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::fmt::Debug for #error {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                f.debug_struct(::core::stringify!(#error))
+                    .field("input", &self.input())
+                    .field("suggestion", &self.suggestion)
+                    .finish()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::fmt::Display for #error {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                let ellipsis = if self.truncated { "..." } else { "" };
+                ::core::write!(f, "unknown variant \"{}{}\" of {}", self.input(), ellipsis, #enum_name)?;
+                match self.suggestion {
+                    ::core::option::Option::Some(name) => ::core::write!(f, ", did you mean \"{}\"?", name),
+                    ::core::option::Option::None => ::core::result::Result::Ok(()),
+                }
+            }
+        }
+    };
+    let error_items = info.namespace(&error, error_items);
+
+    Ok(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            pub const VARIANT_NAMES: [&'static str; #count] = [#(#names, )*];
+            pub fn name(&self) -> &'static str {
+                #name_of
+            }
+            pub fn names() -> ::core::iter::Copied<::core::slice::Iter<'static, &'static str>> {
+                ::core::iter::Iterator::copied(Self::VARIANT_NAMES.iter())
+            }
+            pub fn from_prefix(s: &str) -> ::core::option::Option<Self> {
+                let prefix = s.as_bytes();
+                // (number of matches, index of the last match)
+                let mut whole = (0, 0);
+                let mut partial = (0, 0);
+                for (i, name) in ::core::iter::Iterator::enumerate(Self::VARIANT_NAMES.iter()) {
+                    if *name == s {
+                        return Self::from_index(i);
+                    }
+                    let name = name.as_bytes();
+                    if prefix.is_empty()
+                        || name.len() < prefix.len()
+                        || !name[..prefix.len()].eq_ignore_ascii_case(prefix)
+                    {
+                        continue;
+                    }
+                    let matches = if name.len() == prefix.len() { &mut whole } else { &mut partial };
+                    *matches = (matches.0 + 1, i);
+                }
+                match (whole, partial) {
+                    ((1, i), _) | ((0, _), (1, i)) => Self::from_index(i),
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+
+        #error_items
+
+        #[allow(deprecated)]
+        impl ::core::str::FromStr for #name {
+            type Err = #error;
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                const SORTED: [(&str, usize); #sorted_count] = [#((#sorted_names, #sorted_indices), )*];
+                /// The edit distance between the strings, ignoring ASCII case.
+                /// The second one is a variant name, which bounds the length of the row.
+                fn distance(a: &[u8], b: &[u8]) -> usize {
+                    let mut row = [0; #max_len + 1];
+                    for (j, cell) in ::core::iter::Iterator::enumerate(row.iter_mut()) {
+                        *cell = j;
+                    }
+                    for (i, ca) in ::core::iter::Iterator::enumerate(a.iter()) {
+                        let mut diagonal = row[0];
+                        row[0] = i + 1;
+                        for (j, cb) in ::core::iter::Iterator::enumerate(b.iter()) {
+                            let cost = if ca.eq_ignore_ascii_case(cb) { 0 } else { 1 };
+                            let next = ::core::cmp::Ord::min(::core::cmp::Ord::min(row[j], row[j + 1]) + 1, diagonal + cost);
+                            diagonal = row[j + 1];
+                            row[j + 1] = next;
+                        }
+                    }
+                    row[b.len()]
+                }
+
+                match SORTED.binary_search_by(|(name, _)| ::core::cmp::Ord::cmp(*name, s)) {
+                    ::core::result::Result::Ok(found) => ::core::result::Result::Ok(Self::from_index(SORTED[found].1).unwrap()),
+                    ::core::result::Result::Err(_) => {
+                        // Suggest the closest name unless it differs in more than about a third of it.
+                        let suggestion = ::core::iter::Iterator::min_by_key(
+                            ::core::iter::Iterator::filter(
+                                ::core::iter::Iterator::map(Self::VARIANT_NAMES.iter(), |name| (*name, distance(s.as_bytes(), name.as_bytes()))),
+                                |(name, distance)| *distance * 3 <= name.len() + 2,
+                            ),
+                            |(_, distance)| *distance,
+                        );
+                        ::core::result::Result::Err(#error::new(s, suggestion.map(|(name, _)| name)))
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Reads the former names of a variant from `#[name(renamed_from = "OldName")]`, which `FromStr` still accepts.
+fn renamed_from(attrs: &[Attribute]) -> syn::Result<Vec<LitStr>> {
+    options(attrs, "name")?
+        .into_iter()
+        .map(|option| match option {
+            Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(lit),
+                ..
+            }) if path.is_ident("renamed_from") => Ok(lit),
+            option => Err(syn::Error::new_spanned(
+                option,
+                "unknown name option, expected renamed_from = \"OldName\"",
+            )),
+        })
+        .collect()
+}
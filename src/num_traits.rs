@@ -0,0 +1,96 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Implements num-traits' `Bounded`, `FromPrimitive` and `ToPrimitive` if the enum has `#[rotate(num_traits)]`.
+///
+/// The bounds are the first and the last variant. The primitives are the indices of the variants,
+/// or their discriminants if any variant declares one, like `as` would convert them.
+pub(crate) fn generate(info: &EnumInfo) -> Option<TokenStream> {
+    if !info.options.num_traits {
+        return None;
+    }
+    let name = info.name;
+    let patterns = info.variant_patterns(&quote! { Self });
+    let variants = &info.variants;
+
+    let primitives = if info.has_discriminants {
+        // i128 holds the discriminants of every repr but u128, so they can be compared and converted exactly.
+        quote! {
+            #[allow(deprecated)]
+            impl ::num_traits::FromPrimitive for #name {
+                fn from_i64(n: i64) -> ::core::option::Option<Self> {
+                    #(if n as i128 == Self::#variants as i128 {
+                        return ::core::option::Option::Some(Self::#variants);
+                    })*
+                    ::core::option::Option::None
+                }
+                fn from_u64(n: u64) -> ::core::option::Option<Self> {
+                    #(if n as i128 == Self::#variants as i128 {
+                        return ::core::option::Option::Some(Self::#variants);
+                    })*
+                    ::core::option::Option::None
+                }
+            }
+
+            #[allow(deprecated)]
+            impl ::num_traits::ToPrimitive for #name {
+                fn to_i64(&self) -> ::core::option::Option<i64> {
+                    let discriminant = match *self {
+                        #(#patterns => Self::#variants as i128, )*
+                    };
+                    ::core::result::Result::ok(::core::convert::TryFrom::try_from(discriminant))
+                }
+                fn to_u64(&self) -> ::core::option::Option<u64> {
+                    let discriminant = match *self {
+                        #(#patterns => Self::#variants as i128, )*
+                    };
+                    ::core::result::Result::ok(::core::convert::TryFrom::try_from(discriminant))
+                }
+            }
+        }
+    } else {
+        quote! {
+            #[allow(deprecated)]
+            impl ::num_traits::FromPrimitive for #name {
+                fn from_i64(n: i64) -> ::core::option::Option<Self> {
+                    ::core::result::Result::ok(::core::convert::TryFrom::try_from(n)).and_then(Self::from_index)
+                }
+                fn from_u64(n: u64) -> ::core::option::Option<Self> {
+                    ::core::result::Result::ok(::core::convert::TryFrom::try_from(n)).and_then(Self::from_index)
+                }
+            }
+
+            #[allow(deprecated)]
+            impl ::num_traits::ToPrimitive for #name {
+                fn to_i64(&self) -> ::core::option::Option<i64> {
+                    ::core::result::Result::ok(::core::convert::TryFrom::try_from(self.index()))
+                }
+                fn to_u64(&self) -> ::core::option::Option<u64> {
+                    ::core::result::Result::ok(::core::convert::TryFrom::try_from(self.index()))
+                }
+            }
+        }
+    };
+
+    // An empty enum has no value to return.
+    let bounded = match (variants.first(), variants.last()) {
+        (Some(first), Some(last)) => quote! {
+            #[allow(deprecated)]
+            impl ::num_traits::Bounded for #name {
+                fn min_value() -> Self {
+                    Self::#first
+                }
+                fn max_value() -> Self {
+                    Self::#last
+                }
+            }
+        },
+        _ => quote! {},
+    };
+
+    Some(quote! {
+        #bounded
+        #primitives
+    })
+}
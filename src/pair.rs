@@ -0,0 +1,42 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+/// Generates `flipped()` if the variants have `#[pair(...)]` attributes.
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<Option<TokenStream>> {
+    let pairs = match info.variant_args::<LitStr>("pair")? {
+        Some(pairs) => pairs,
+        None => return Ok(None),
+    };
+    let indices = pairs
+        .iter()
+        .map(|pair| info.find_variant(pair))
+        .collect::<syn::Result<Vec<_>>>()?;
+    for (i, (&pair, lit)) in indices.iter().zip(&pairs).enumerate() {
+        if indices[pair] != i {
+            let (variant, other) = (info.variants[i], info.variants[pair]);
+            return Err(syn::Error::new_spanned(
+                lit,
+                format!(
+                    "{} is paired with {}, but {} is paired with {}",
+                    variant, other, other, info.variants[indices[pair]]
+                ),
+            ));
+        }
+    }
+    let name = info.name;
+    let patterns = info.variant_patterns(&quote! { Self });
+    let flipped = indices.iter().map(|&pair| info.variants[pair]);
+
+    Ok(Some(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            pub fn flipped(self) -> Self {
+                match self {
+                    #(#patterns => Self::#flipped, )*
+                }
+            }
+        }
+    }))
+}
@@ -0,0 +1,44 @@
+use crate::{model::EnumInfo, tools::Tools};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the methods that take a random number generator of rand if the enum has `#[rotate(rand)]`.
+///
+/// `shuffled_iter()` only needs the indices, while `random_step()` is only for the enums that rotate.
+pub(crate) fn generate(info: &EnumInfo, tools: &Tools) -> Option<TokenStream> {
+    if !info.options.rand {
+        return None;
+    }
+
+    let name = info.name;
+    let count = info.variants.len();
+    let indices = info.indices();
+
+    let random_step = if tools.rotate {
+        quote! {
+            pub fn random_step(self, rng: &mut impl ::rand::Rng) -> Self {
+                if ::rand::Rng::gen_bool(rng, 0.5) {
+                    self.next()
+                } else {
+                    self.prev()
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Some(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            #random_step
+            pub fn shuffled_iter(rng: &mut impl ::rand::Rng) -> impl ::core::iter::ExactSizeIterator<Item = Self> {
+                let mut indices: [usize; #count] = [#(#indices),*];
+                ::rand::seq::SliceRandom::shuffle(&mut indices[..], rng);
+                ::core::iter::Iterator::map(::core::iter::IntoIterator::into_iter(indices), |index| {
+                    Self::from_index(index).unwrap()
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,74 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Meta, NestedMeta};
+
+/// The integer types that `#[repr(...)]` accepts.
+const INTEGER_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// Generates `to_repr()` and `TryFrom` of the integer type given with `#[repr(...)]` if
+/// `#[rotate(repr_conversions)]` is given. They convert by the discriminants rather than the indices,
+/// so that they work for signed types and negative discriminants just like `as` does.
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<Option<TokenStream>> {
+    if !info.options.repr_conversions {
+        return Ok(None);
+    }
+    let repr = info
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .find_map(|nested| match nested {
+            NestedMeta::Meta(Meta::Path(path)) => path
+                .get_ident()
+                .filter(|ident| INTEGER_TYPES.iter().any(|ty| *ident == ty))
+                .cloned(),
+            _ => None,
+        });
+    let repr = match repr {
+        Some(repr) => repr,
+        None => {
+            return Err(syn::Error::new_spanned(
+                info.name,
+                "repr_conversions needs an integer #[repr(...)] on the enum",
+            ))
+        }
+    };
+    // `as` only casts enums without fields, and the skipped variants have no discriminant to convert from.
+    if let Some(skipped) = info.skipped.first() {
+        return Err(syn::Error::new_spanned(
+            skipped.ident,
+            "repr_conversions cannot be combined with variants skipped by skip_data",
+        ));
+    }
+    let name = info.name;
+    let variants = &info.variants;
+
+    Ok(Some(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            pub fn to_repr(&self) -> #repr {
+                match *self {
+                    #(Self::#variants => Self::#variants as #repr, )*
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::convert::TryFrom<#repr> for #name {
+            type Error = #repr;
+            fn try_from(value: #repr) -> ::core::result::Result<Self, #repr> {
+                #(if value == Self::#variants as #repr {
+                    return ::core::result::Result::Ok(Self::#variants);
+                })*
+                ::core::result::Result::Err(value)
+            }
+        }
+    }))
+}
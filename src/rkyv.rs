@@ -0,0 +1,73 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+
+/// Implements rkyv's `Archive`, `Serialize` and `Deserialize` if the enum has `#[rotate(rkyv)]`.
+///
+/// The enum is archived as the index of the variant in a `u32`, so the archive does not depend on
+/// the layout of the enum and stays valid as long as the variants keep their indices.
+/// An index out of range is returned as an error through the deserializer.
+pub(crate) fn generate(info: &EnumInfo) -> Option<TokenStream> {
+    if !info.options.rkyv {
+        return None;
+    }
+
+    let name = info.name;
+    let vis = info.type_vis();
+    let error = info.derived_ident("ArchiveError");
+    let message = format!("invalid archived {} index ", name.unraw());
+
+    let error_items = quote! {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        #vis struct #error {
+            /// The archived index, which is not the index of any variant.
+            pub index: u32,
+        }
+
+        #[allow(deprecated)]
+        impl ::core::fmt::Display for #error {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                f.write_str(#message)?;
+                ::core::fmt::Display::fmt(&self.index, f)
+            }
+        }
+    };
+    let error_items = info.namespace(&error, error_items);
+
+    Some(quote! {
+        #error_items
+
+        #[allow(deprecated)]
+        impl ::rkyv::Archive for #name {
+            type Archived = ::rkyv::Archived<u32>;
+            type Resolver = ();
+            #[inline]
+            unsafe fn resolve(&self, pos: usize, resolver: (), out: *mut Self::Archived) {
+                ::rkyv::Archive::resolve(&(self.index() as u32), pos, resolver, out)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<S: ::rkyv::Fallible + ?::core::marker::Sized> ::rkyv::Serialize<S> for #name {
+            #[inline]
+            fn serialize(&self, _: &mut S) -> ::core::result::Result<(), S::Error> {
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<D: ::rkyv::Fallible + ?::core::marker::Sized> ::rkyv::Deserialize<#name, D>
+            for ::rkyv::Archived<u32>
+        where
+            D::Error: ::core::convert::From<#error>,
+        {
+            #[inline]
+            fn deserialize(&self, deserializer: &mut D) -> ::core::result::Result<#name, D::Error> {
+                let index: u32 = ::rkyv::Deserialize::<u32, D>::deserialize(self, deserializer)?;
+                #name::from_index(index as usize)
+                    .ok_or_else(|| ::core::convert::From::from(#error { index }))
+            }
+        }
+    })
+}
@@ -0,0 +1,316 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<TokenStream> {
+    let name = info.name;
+    let toggle = if info.variants.len() == 2 {
+        quote! {
+            pub fn toggle(self) -> Self {
+                self.next()
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let aliases = if let Some((next, prev)) = &info.options.aliases {
+        quote! {
+            pub fn #next(self) -> Self {
+                self.next()
+            }
+            pub fn #prev(self) -> Self {
+                self.prev()
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Variants marked with `#[rotate(self_loop)]` rotate to themselves and are skipped by the others,
+    // so the neighbors are looked up in tables computed here instead of by index arithmetic.
+    let (next_index, prev_index) = if info.self_loops.iter().any(|self_loop| *self_loop) {
+        let count = info.variants.len();
+        let step = |index: usize, forward: bool| {
+            if info.self_loops[index] {
+                return index;
+            }
+            let mut next = index;
+            loop {
+                next = (if forward { next + 1 } else { next + count - 1 }) % count;
+                if !info.self_loops[next] {
+                    return next;
+                }
+            }
+        };
+        let next = (0..count).map(|index| step(index, true));
+        let prev = (0..count).map(|index| step(index, false));
+        (
+            quote! { [#(#next),*][index] },
+            quote! { [#(#prev),*][index] },
+        )
+    } else {
+        (
+            quote! { if index + 1 < Self::COUNT { index + 1 } else { 0 } },
+            quote! { if 0 < index { index } else { Self::COUNT } - 1 },
+        )
+    };
+
+    // The variants skipped by `#[rotate(skip_data)]` rotate to the unit variants declared around them,
+    // or the nearest ones that aren't self loops, since the others skip over those.
+    let (skipped_next, skipped_prev, skipped_neighbors) = if info.skipped.is_empty() {
+        (quote! {}, quote! {}, quote! {})
+    } else {
+        let count = info.variants.len();
+        let land = |mut index: usize, forward: bool| {
+            for _ in 0..count {
+                if !info.self_loops[index] {
+                    break;
+                }
+                index = (if forward {
+                    index + 1
+                } else {
+                    index + count - 1
+                }) % count;
+            }
+            info.variants[index]
+        };
+        let skipped = &info.skipped_idents();
+        let next = &info
+            .skipped
+            .iter()
+            .map(|skipped| land(skipped.index, true))
+            .collect::<Vec<_>>();
+        let prev = &info
+            .skipped
+            .iter()
+            .map(|skipped| land(skipped.prev, false))
+            .collect::<Vec<_>>();
+        (
+            quote! {
+                #(if let Self::#skipped { .. } = self {
+                    return Self::#next;
+                })*
+            },
+            quote! {
+                #(if let Self::#skipped { .. } = self {
+                    return Self::#prev;
+                })*
+            },
+            quote! {
+                #(if let Self::#skipped { .. } = self {
+                    return (Self::#prev, Self::#next);
+                })*
+            },
+        )
+    };
+
+    // With self loops, the methods that step several times at once work on the cycle of the variants
+    // that rotate, which `next()` goes through, and leave the self loops where they are.
+    let arithmetic = if info.self_loops.iter().any(|self_loop| *self_loop) {
+        let count = info.variants.len();
+        let cycle = (0..count)
+            .filter(|index| !info.self_loops[*index])
+            .collect::<Vec<_>>();
+        let cycle_len = cycle.len();
+        let positions = (0..count).map(|index| {
+            cycle
+                .iter()
+                .position(|cycled| *cycled == index)
+                .unwrap_or(cycle_len)
+        });
+        // The indices of the variants that rotate, and the position of each variant among them,
+        // which is the length of the cycle for the self loops.
+        let tables = quote! {
+            const CYCLE: [usize; #cycle_len] = [#(#cycle),*];
+            const POSITION: [usize; #count] = [#(#positions),*];
+        };
+        quote! {
+            pub fn wrapping_add(self, n: usize) -> Self {
+                #tables
+                let position = POSITION[self.index()];
+                if position == CYCLE.len() {
+                    return self;
+                }
+                let position = position + n.rem_euclid(CYCLE.len());
+                Self::from_index(CYCLE[position.rem_euclid(CYCLE.len())]).unwrap()
+            }
+            pub fn wrapping_sub(self, n: usize) -> Self {
+                #tables
+                let position = POSITION[self.index()];
+                if position == CYCLE.len() {
+                    return self;
+                }
+                let position = position + CYCLE.len() - n.rem_euclid(CYCLE.len());
+                Self::from_index(CYCLE[position.rem_euclid(CYCLE.len())]).unwrap()
+            }
+            pub fn advance_wrapping(self, n: usize) -> (Self, usize) {
+                #tables
+                let position = POSITION[self.index()];
+                if position == CYCLE.len() {
+                    return (self, 0);
+                }
+                let position = position + n.rem_euclid(CYCLE.len());
+                let wraps = n.div_euclid(CYCLE.len()) + position.div_euclid(CYCLE.len());
+                (Self::from_index(CYCLE[position.rem_euclid(CYCLE.len())]).unwrap(), wraps)
+            }
+            pub fn cmp_from(self, anchor: Self, other: Self) -> ::core::cmp::Ordering {
+                #tables
+                let anchor = anchor.index();
+                // The self loops cannot be reached from the other variants, nor the others from them.
+                let distance = |index: usize| {
+                    let (from, to) = (POSITION[anchor], POSITION[index]);
+                    if index == anchor {
+                        0
+                    } else if from == CYCLE.len() || to == CYCLE.len() {
+                        CYCLE.len()
+                    } else if from <= to {
+                        to - from
+                    } else {
+                        to + CYCLE.len() - from
+                    }
+                };
+                ::core::cmp::Ord::cmp(&distance(self.index()), &distance(other.index()))
+            }
+            pub fn path_to(self, target: Self) -> impl ::core::iter::Iterator<Item = Self> {
+                #tables
+                let (from, to) = (POSITION[self.index()], POSITION[target.index()]);
+                let steps = if from == CYCLE.len() || to == CYCLE.len() {
+                    0
+                } else if from <= to {
+                    to - from
+                } else {
+                    to + CYCLE.len() - from
+                };
+                ::core::iter::Iterator::map(1..=steps, move |step| {
+                    Self::from_index(CYCLE[(from + step).rem_euclid(CYCLE.len())]).unwrap()
+                })
+            }
+            pub fn shortest_path_to(self, target: Self) -> impl ::core::iter::Iterator<Item = Self> {
+                #tables
+                let (from, to) = (POSITION[self.index()], POSITION[target.index()]);
+                let (forward, backward) = if from == CYCLE.len() || to == CYCLE.len() {
+                    (0, 0)
+                } else {
+                    let forward = if from <= to { to - from } else { to + CYCLE.len() - from };
+                    (forward, (CYCLE.len() - forward).rem_euclid(CYCLE.len()))
+                };
+                let steps = ::core::cmp::Ord::min(forward, backward);
+                ::core::iter::Iterator::map(1..=steps, move |step| {
+                    let position = if forward <= backward { from + step } else { from + CYCLE.len() - step };
+                    Self::from_index(CYCLE[position.rem_euclid(CYCLE.len())]).unwrap()
+                })
+            }
+            pub fn by_distance_from(anchor: Self) -> impl ::core::iter::Iterator<Item = Self> {
+                #tables
+                let anchor_index = anchor.index();
+                let anchor = POSITION[anchor_index];
+                // The variants that rotate by their distance from the anchor, and then the self loops,
+                // or the anchor and then the others in index order if the anchor is a self loop.
+                let rotating = if anchor == CYCLE.len() { 1 } else { CYCLE.len() };
+                let rotated = ::core::iter::Iterator::map(0..rotating, move |order| {
+                    if anchor == CYCLE.len() {
+                        return anchor_index;
+                    }
+                    let distance = order - order / 2;
+                    let position = if order % 2 == 1 { anchor + distance } else { anchor + CYCLE.len() - distance };
+                    CYCLE[position.rem_euclid(CYCLE.len())]
+                });
+                let rest = ::core::iter::Iterator::filter(0..Self::COUNT, move |index| {
+                    *index != anchor_index && (anchor == CYCLE.len() || POSITION[*index] == CYCLE.len())
+                });
+                ::core::iter::Iterator::map(::core::iter::Iterator::chain(rotated, rest), |index| {
+                    Self::from_index(index).unwrap()
+                })
+            }
+        }
+    } else {
+        quote! {
+            pub fn wrapping_add(self, n: usize) -> Self {
+                let index = self.index() + n.rem_euclid(Self::COUNT);
+                Self::from_index(index.rem_euclid(Self::COUNT)).unwrap()
+            }
+            pub fn wrapping_sub(self, n: usize) -> Self {
+                let index = self.index() + Self::COUNT - n.rem_euclid(Self::COUNT);
+                Self::from_index(index.rem_euclid(Self::COUNT)).unwrap()
+            }
+            pub fn advance_wrapping(self, n: usize) -> (Self, usize) {
+                let index = self.index() + n.rem_euclid(Self::COUNT);
+                let wraps = n.div_euclid(Self::COUNT) + index.div_euclid(Self::COUNT);
+                (Self::from_index(index.rem_euclid(Self::COUNT)).unwrap(), wraps)
+            }
+            pub fn cmp_from(self, anchor: Self, other: Self) -> ::core::cmp::Ordering {
+                let anchor = anchor.index();
+                let distance = |index: usize| {
+                    if anchor <= index {
+                        index - anchor
+                    } else {
+                        index + Self::COUNT - anchor
+                    }
+                };
+                ::core::cmp::Ord::cmp(&distance(self.index()), &distance(other.index()))
+            }
+            pub fn path_to(self, target: Self) -> impl ::core::iter::Iterator<Item = Self> {
+                let (from, to) = (self.index(), target.index());
+                let steps = if from <= to { to - from } else { to + Self::COUNT - from };
+                ::core::iter::Iterator::map(1..=steps, move |step| {
+                    Self::from_index((from + step).rem_euclid(Self::COUNT)).unwrap()
+                })
+            }
+            pub fn shortest_path_to(self, target: Self) -> impl ::core::iter::Iterator<Item = Self> {
+                let (from, to) = (self.index(), target.index());
+                let forward = if from <= to { to - from } else { to + Self::COUNT - from };
+                let backward = (Self::COUNT - forward).rem_euclid(Self::COUNT);
+                let steps = ::core::cmp::Ord::min(forward, backward);
+                ::core::iter::Iterator::map(1..=steps, move |step| {
+                    let index = if forward <= backward { from + step } else { from + Self::COUNT - step };
+                    Self::from_index(index.rem_euclid(Self::COUNT)).unwrap()
+                })
+            }
+            pub fn by_distance_from(anchor: Self) -> impl ::core::iter::Iterator<Item = Self> {
+                let anchor = anchor.index();
+                ::core::iter::Iterator::map(0..Self::COUNT, move |order| {
+                    // 0, +1, -1, +2, -2, ... from the anchor, which covers every index once.
+                    let distance = order - order / 2;
+                    let index = if order % 2 == 1 { anchor + distance } else { anchor + Self::COUNT - distance };
+                    Self::from_index(index.rem_euclid(Self::COUNT)).unwrap()
+                })
+            }
+        }
+    };
+
+    Ok(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            pub fn next(self) -> Self {
+                #skipped_next
+                let index = self.index();
+                Self::from_index(#next_index).unwrap()
+            }
+            pub fn prev(self) -> Self {
+                #skipped_prev
+                let index = self.index();
+                Self::from_index(#prev_index).unwrap()
+            }
+            pub fn neighbors(self) -> (Self, Self) {
+                #skipped_neighbors
+                let index = self.index();
+                let prev = #prev_index;
+                let next = #next_index;
+                (Self::from_index(prev).unwrap(), Self::from_index(next).unwrap())
+            }
+            #arithmetic
+            pub fn all_rotations() -> impl ::core::iter::Iterator<
+                Item = impl ::core::iter::Iterator<Item = Self> + ::core::clone::Clone,
+            > + ::core::clone::Clone {
+                ::core::iter::Iterator::map(0..Self::COUNT, |start| {
+                    ::core::iter::Iterator::map(0..Self::COUNT, move |step| {
+                        Self::from_index((start + step).rem_euclid(Self::COUNT)).unwrap()
+                    })
+                })
+            }
+            #toggle
+            #aliases
+        }
+    })
+}
@@ -0,0 +1,96 @@
+use crate::{model::EnumInfo, tools::Tools};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// Implements the traits of rotate-enum-runtime and the conversions to its `CyclicIndex`
+/// if `#[rotate(runtime)]` is given.
+pub(crate) fn generate(info: &EnumInfo, tools: &Tools) -> Option<TokenStream> {
+    if !info.options.runtime {
+        return None;
+    }
+
+    let name = info.name;
+    let krate = info.runtime_crate();
+    let variants = &info.variants;
+    let count = variants.len();
+    // The largest index is `count - 1`, so e.g. 256 variants still fit in a `u8`.
+    let index_type = match count {
+        0..=0x100 => quote! { u8 },
+        0x101..=0x1_0000 => quote! { u16 },
+        _ if count as u64 <= 0x1_0000_0000 => quote! { u32 },
+        _ => quote! { usize },
+    };
+    // The generated `next()` and `prev()` skip the variants that aren't neighbors by index,
+    // e.g. with `#[rotate(self_loop)]`, so the runtime rotates through them instead.
+    let steps = if tools.rotate {
+        quote! {
+            fn next_index(index: usize) -> usize {
+                Self::from_index(index).unwrap().next().index()
+            }
+            fn prev_index(index: usize) -> usize {
+                Self::from_index(index).unwrap().prev().index()
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Some(quote! {
+        #[allow(deprecated)]
+        impl #krate::Rotate for #name {
+            const COUNT: usize = Self::COUNT;
+            type Index = #index_type;
+            fn index(&self) -> usize {
+                Self::index(self)
+            }
+            fn from_index(index: usize) -> ::core::option::Option<Self> {
+                Self::from_index(index)
+            }
+            #steps
+        }
+
+        #[allow(deprecated)]
+        impl #krate::EnumVariants for #name {
+            fn variants() -> &'static [Self] {
+                static VARIANTS: [#name; #count] = [#(#name::#variants, )*];
+                &VARIANTS
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::convert::From<#name> for #krate::CyclicIndex<#count> {
+            fn from(value: #name) -> Self {
+                Self::new(value.index()).unwrap()
+            }
+        }
+
+        #[allow(deprecated)]
+        impl ::core::convert::From<#krate::CyclicIndex<#count>> for #name {
+            fn from(index: #krate::CyclicIndex<#count>) -> Self {
+                Self::from_index(index.get()).unwrap()
+            }
+        }
+    })
+}
+
+/// Implements `IntoEnumIterator` of rotate-enum-runtime with the generated iterator
+/// if `#[rotate(runtime)]` is given.
+pub(crate) fn generate_iter(info: &EnumInfo, iterator_name: &Ident) -> Option<TokenStream> {
+    if !info.options.runtime {
+        return None;
+    }
+
+    let name = info.name;
+    let krate = info.runtime_crate();
+
+    Some(quote! {
+        #[allow(deprecated)]
+        impl #krate::IntoEnumIterator for #name {
+            type Iterator = #iterator_name;
+            fn iter() -> #iterator_name {
+                #iterator_name::new()
+            }
+        }
+    })
+}
@@ -0,0 +1,37 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+
+/// Implements schemars' `JsonSchema` if the enum has `#[rotate(schemars)]`.
+///
+/// The schema is a string that is one of the variant names, which is how serde represents unit variants by default.
+pub(crate) fn generate(info: &EnumInfo) -> Option<TokenStream> {
+    if !info.options.schemars {
+        return None;
+    }
+
+    let name = info.name;
+    let schema_name = name.unraw().to_string();
+    let names = info.variant_names();
+
+    Some(quote! {
+        #[allow(deprecated)]
+        impl ::schemars::JsonSchema for #name {
+            fn schema_name() -> ::std::string::String {
+                ::std::borrow::ToOwned::to_owned(#schema_name)
+            }
+            fn json_schema(_: &mut ::schemars::gen::SchemaGenerator) -> ::schemars::schema::Schema {
+                ::schemars::schema::Schema::Object(::schemars::schema::SchemaObject {
+                    instance_type: ::core::option::Option::Some(::core::convert::Into::into(
+                        ::schemars::schema::InstanceType::String,
+                    )),
+                    enum_values: ::core::option::Option::Some(::std::vec![
+                        #(::core::convert::Into::into(#names), )*
+                    ]),
+                    ..::core::default::Default::default()
+                })
+            }
+        }
+    })
+}
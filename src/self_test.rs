@@ -0,0 +1,288 @@
+use crate::{model::EnumInfo, shift, tools::Tools};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, Ident};
+
+/// Names the module of the generated tests, e.g. `__rotate_enum_self_test_Direction_count_rotate`.
+/// Several derives on the same enum can each generate tests, so the module is named after the tools too.
+pub(crate) fn module_ident(info: &EnumInfo, tools: &Tools, kind: &str) -> Ident {
+    let tools_names = [
+        (tools.count, "count"),
+        (tools.rotate, "rotate"),
+        (tools.shift, "shift"),
+        (tools.bounce, "bounce"),
+        (tools.iter, "iter"),
+        (tools.names, "names"),
+    ]
+    .iter()
+    .filter(|(enabled, _)| *enabled)
+    .map(|(_, tool)| *tool)
+    .collect::<Vec<_>>();
+    Ident::new(
+        &format!(
+            "__rotate_enum_{}_{}_{}",
+            kind,
+            info.name.unraw(),
+            tools_names.join("_")
+        ),
+        info.name.span(),
+    )
+}
+
+/// Generates `#[cfg(test)]` tests that check the invariants of the generated methods
+/// for this particular enum, if `#[rotate(self_test)]` is given.
+pub(crate) fn generate(info: &EnumInfo, tools: &Tools) -> Option<TokenStream> {
+    if !info.options.self_test || info.carries_data() {
+        return None;
+    }
+
+    let name = info.name;
+    let patterns = info.variant_patterns(&quote! { #name });
+    let variants = &info.variants;
+    let indices = info.indices();
+    let count = variants.len();
+    let module = module_ident(info, tools, "self_test");
+
+    let mut tests = vec![];
+    if tools.count {
+        tests.push(quote! {
+            #[test]
+            fn index() {
+                assert_eq!(#name::COUNT, COUNT);
+                for i in 0..COUNT {
+                    assert_eq!(variant(i).index(), i);
+                    assert_eq!(#name::from_index(i).map(|v| index_of(&v)), Some(i));
+                    assert_eq!(index_of(&#name::from_index_wrapping(i + COUNT)), i);
+                    assert_eq!(index_of(&#name::from_index_clamped(i)), i);
+                }
+                if COUNT > 0 {
+                    assert_eq!(index_of(&#name::from_index_clamped(COUNT)), COUNT - 1);
+                }
+                assert!(#name::from_index(COUNT).is_none());
+                let mut visited = 0;
+                #name::for_each(|v| {
+                    assert_eq!(index_of(&v), visited);
+                    visited += 1;
+                });
+                assert_eq!(visited, COUNT);
+                let indices = #name::map_variants(|v| index_of(&v));
+                for i in 0..COUNT {
+                    assert_eq!(indices[i], i);
+                }
+            }
+        });
+    }
+    if tools.rotate {
+        let self_loops = &info.self_loops;
+        tests.push(quote! {
+            #[test]
+            fn rotate() {
+                const SELF_LOOP: [bool; COUNT] = [#(#self_loops),*];
+                for i in 0..COUNT {
+                    let next = if SELF_LOOP[i] {
+                        i
+                    } else {
+                        (1..=COUNT).map(|k| (i + k).rem_euclid(COUNT)).find(|j| !SELF_LOOP[*j]).unwrap()
+                    };
+                    assert_eq!(index_of(&variant(i).next()), next);
+                    assert_eq!(index_of(&variant(i).next().prev()), i);
+                    assert_eq!(index_of(&variant(i).prev().next()), i);
+                    // Stepping several times at once goes where next() goes, counting the wraps past the last variant.
+                    let mut stepped = (i, 0);
+                    for n in 0..COUNT * 2 + 1 {
+                        assert_eq!(index_of(&variant(i).wrapping_add(n)), stepped.0);
+                        assert_eq!(index_of(&variant(i).wrapping_add(n).wrapping_sub(n)), i);
+                        let (advanced, wraps) = variant(i).advance_wrapping(n);
+                        assert_eq!((index_of(&advanced), wraps), stepped);
+                        let next = index_of(&variant(stepped.0).next());
+                        let wrapped = !SELF_LOOP[i] && next <= stepped.0;
+                        stepped = (next, stepped.1 + wrapped as usize);
+                    }
+                    let (prev, next) = variant(i).neighbors();
+                    assert_eq!(index_of(&prev), index_of(&variant(i).prev()));
+                    assert_eq!(index_of(&next), index_of(&variant(i).next()));
+                    // The number of next() and prev() steps to each variant, or COUNT if it cannot be reached.
+                    let mut forward = [COUNT; COUNT];
+                    let mut backward = [COUNT; COUNT];
+                    let (mut ahead, mut behind) = (i, i);
+                    for k in 0..COUNT {
+                        forward[ahead] = ::core::cmp::min(forward[ahead], k);
+                        backward[behind] = ::core::cmp::min(backward[behind], k);
+                        ahead = index_of(&variant(ahead).next());
+                        behind = index_of(&variant(behind).prev());
+                    }
+                    for j in 0..COUNT {
+                        let path = variant(i).path_to(variant(j));
+                        let reachable = i != j && forward[j] < COUNT;
+                        assert_eq!(path.last().map(|v| index_of(&v)), if reachable { Some(j) } else { None });
+                        let steps = if reachable { ::core::cmp::min(forward[j], backward[j]) } else { 0 };
+                        assert_eq!(variant(i).shortest_path_to(variant(j)).count(), steps);
+                        for k in 0..COUNT {
+                            assert_eq!(variant(j).cmp_from(variant(i), variant(k)), ::core::cmp::Ord::cmp(&forward[j], &forward[k]));
+                        }
+                    }
+                    let mut order = #name::by_distance_from(variant(i)).map(|v| index_of(&v)).collect::<::std::vec::Vec<_>>();
+                    assert_eq!(order.first(), Some(&i));
+                    order.sort_unstable();
+                    assert_eq!(order, (0..COUNT).collect::<::std::vec::Vec<_>>());
+                }
+            }
+        });
+    }
+    if tools.shift {
+        let overflow = match shift::shift_options(info).map(|options| options.overflow_to) {
+            Ok(Some(index)) => quote! { Some(#index) },
+            _ => quote! { None },
+        };
+        let next = shift::next_fn(info);
+        tests.push(quote! {
+            #[test]
+            fn shift() {
+                let overflow: ::core::option::Option<usize> = #overflow;
+                for i in 0..COUNT {
+                    let has_next = i + 1 < COUNT;
+                    assert_eq!(variant(i).next().map(|v| index_of(&v)), if has_next { Some(i + 1) } else { overflow });
+                    assert_eq!(variant(i).prev().map(|v| index_of(&v)), i.checked_sub(1).or(overflow));
+                    assert_eq!(index_of(&variant(i).next_or(variant(i))), if has_next { i + 1 } else { overflow.unwrap_or(i) });
+                    assert_eq!(index_of(&variant(i).prev_or(variant(i))), if 0 < i { i - 1 } else { overflow.unwrap_or(i) });
+                    if has_next {
+                        assert_eq!(variant(i).next().and_then(#name::prev).map(|v| index_of(&v)), Some(i));
+                    }
+                    if 0 < i {
+                        assert_eq!(variant(i).prev().and_then(#next).map(|v| index_of(&v)), Some(i));
+                    }
+                    for j in 0..COUNT {
+                        let n = j as isize - i as isize;
+                        assert_eq!(variant(i).checked_add(n).map(|v| index_of(&v)), Some(j));
+                        assert_eq!(variant(i).checked_sub(-n).map(|v| index_of(&v)), Some(j));
+                    }
+                    assert!(variant(i).checked_add((COUNT - i) as isize).is_none());
+                    assert!(variant(i).checked_sub(i as isize + 1).is_none());
+                    assert_eq!(index_of(&variant(i).saturating_add(COUNT as isize)), COUNT - 1);
+                    assert_eq!(index_of(&variant(i).saturating_sub(COUNT as isize)), 0);
+                    let (shifted, steps) = variant(i).shift_by(-(COUNT as isize));
+                    assert_eq!((index_of(&shifted), steps), (0, -(i as isize)));
+                    assert_eq!(variant(i).remaining_forward(), COUNT - 1 - i);
+                    assert_eq!(variant(i).remaining_backward(), i);
+                    // The methods that take several steps agree with stepping one variant at a time,
+                    // which bridges over the variants skipped by skip_data.
+                    if overflow.is_none() {
+                        let (mut forward, mut current) = (0, #next(variant(i)));
+                        while let Some(stepped) = current {
+                            forward += 1;
+                            assert_eq!(variant(i).checked_add(forward).map(|v| index_of(&v)), Some(index_of(&stepped)));
+                            current = #next(stepped);
+                        }
+                        assert_eq!(variant(i).remaining_forward() as isize, forward);
+                        let (mut backward, mut current) = (0, variant(i).prev());
+                        while let Some(stepped) = current {
+                            backward += 1;
+                            assert_eq!(variant(i).checked_sub(backward).map(|v| index_of(&v)), Some(index_of(&stepped)));
+                            current = stepped.prev();
+                        }
+                        assert_eq!(variant(i).remaining_backward() as isize, backward);
+                    }
+                }
+            }
+        });
+    }
+    if tools.bounce {
+        tests.push(quote! {
+            #[test]
+            fn bounce() {
+                for i in 0..COUNT {
+                    for &forward in &[true, false] {
+                        let (bounced, turned) = variant(i).bounce(forward);
+                        let bounced = index_of(&bounced);
+                        if COUNT == 1 {
+                            assert_eq!((bounced, turned), (i, forward));
+                        } else {
+                            assert_eq!(bounced, if turned { i + 1 } else { i - 1 });
+                        }
+                        // A whole period goes to the other end and back.
+                        let period = ::core::cmp::max(2 * (COUNT - 1), 1);
+                        let last = variant(i).bounce_iter(forward).nth(period - 1).map(|v| index_of(&v));
+                        assert_eq!(last, Some(i));
+                    }
+                }
+            }
+        });
+    }
+    if tools.iter {
+        let iterator_name = info.derived_ident("Iterator");
+        tests.push(quote! {
+            #[test]
+            fn iter() {
+                let mut seen = [false; COUNT];
+                let mut iter = super::#iterator_name::new();
+                assert_eq!(iter.len(), COUNT);
+                while let Some(v) = iter.next() {
+                    assert!(!seen[index_of(&v)], "a variant was visited twice");
+                    seen[index_of(&v)] = true;
+                    assert_eq!(iter.len(), seen.iter().filter(|seen| !**seen).count());
+                }
+                assert!(seen.iter().all(|seen| *seen), "a variant was not visited");
+
+                for i in 0..COUNT {
+                    let mut expected = i;
+                    for v in variant(i).iter() {
+                        assert_eq!(index_of(&v), expected);
+                        expected += 1;
+                    }
+                    assert_eq!(expected, COUNT);
+                }
+            }
+        });
+    }
+    if tools.names {
+        tests.push(quote! {
+            #[test]
+            fn names() {
+                assert_eq!(#name::VARIANT_NAMES.len(), COUNT);
+                for i in 0..COUNT {
+                    assert_eq!(variant(i).name(), #name::VARIANT_NAMES[i]);
+                    let parsed = #name::VARIANT_NAMES[i].parse::<#name>();
+                    assert_eq!(parsed.ok().map(|v| index_of(&v)), Some(i));
+                    let prefixed = #name::from_prefix(#name::VARIANT_NAMES[i]);
+                    assert_eq!(prefixed.map(|v| index_of(&v)), Some(i));
+                    for j in 0..i {
+                        assert_ne!(#name::VARIANT_NAMES[i], #name::VARIANT_NAMES[j]);
+                    }
+                }
+            }
+        });
+    }
+
+    Some(quote! {
+        #[cfg(test)]
+        #[allow(non_snake_case, deprecated)]
+        mod #module {
+            // The enum's module may not have the prelude, e.g. with #![no_implicit_prelude].
+            #[allow(unused_imports)]
+            use ::core::{
+                assert, assert_eq, assert_ne,
+                iter::{ExactSizeIterator, Iterator},
+                option::Option::{None, Some},
+                unreachable,
+            };
+            use super::#name;
+
+            const COUNT: usize = #count;
+
+            fn variant(index: usize) -> #name {
+                match index {
+                    #(#indices => #name::#variants, )*
+                    _ => unreachable!(),
+                }
+            }
+
+            fn index_of(variant: &#name) -> usize {
+                match *variant {
+                    #(#patterns => #indices, )*
+                }
+            }
+
+            #(#tests)*
+        }
+    })
+}
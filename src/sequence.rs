@@ -0,0 +1,87 @@
+use crate::{
+    for_each::{define_helper, helper_ident, helper_path},
+    model::EnumInfo,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    Ident, LitInt, Path, Token,
+};
+
+/// Generates the helper macro that evaluates the variant some steps after a given one
+/// in a constant expression, where `next()` cannot be called.
+/// Like the helper of `for_each_variant!`, it is given the path of the enum.
+pub(crate) fn generate(info: &EnumInfo) -> Option<TokenStream> {
+    let (last, variants) = info.variants.split_last()?;
+    let count = info.variants.len();
+    let indices = 0..count;
+    let leading_indices = 0..variants.len();
+    let helper = helper_ident("sequence", info.name);
+    let patterns = info.variant_patterns(&quote! { $($path)* });
+    let (last_pattern, patterns) = patterns.split_last()?;
+
+    Some(define_helper(
+        &helper,
+        quote! {
+            ([$($path:tt)*] $variant:ident, $step:expr) => {{
+                #[allow(deprecated)]
+                const START: usize = match $($path)*::$variant {
+                    #(#patterns => #indices,)*
+                    #last_pattern => #count - 1,
+                };
+                #[allow(deprecated)]
+                const VARIANT: $($path)* = match (START + $step) % #count {
+                    #(#leading_indices => $($path)*::#variants,)*
+                    _ => $($path)*::#last,
+                };
+                VARIANT
+            }};
+        },
+    ))
+}
+
+/// The arguments of `rotate_sequence!(Enum::Variant, length)`.
+pub(crate) struct RotateSequence {
+    path: Path,
+    variant: Ident,
+    length: usize,
+}
+
+impl Parse for RotateSequence {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut path: Path = input.parse()?;
+        if path.segments.len() < 2 {
+            return Err(syn::Error::new_spanned(
+                &path,
+                "expected a variant like Enum::Variant",
+            ));
+        }
+        let variant = path.segments.pop().unwrap().into_value().ident;
+        // Pushing the segment of the enum back drops the `::` that was before the variant.
+        let name = path.segments.pop().unwrap().into_value();
+        path.segments.push(name);
+        input.parse::<Token![,]>()?;
+        let length = input.parse::<LitInt>()?.base10_parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self {
+            path,
+            variant,
+            length,
+        })
+    }
+}
+
+/// Expands `rotate_sequence!` into an array of calls to the helper macro of the enum.
+pub(crate) fn expand(input: RotateSequence) -> TokenStream {
+    let RotateSequence {
+        path,
+        variant,
+        length,
+    } = input;
+    let helper = helper_path(&path, "sequence");
+    let steps = 0..length;
+    quote! {
+        [#(#helper!([#path] #variant, #steps)),*]
+    }
+}
@@ -0,0 +1,193 @@
+use crate::model::{options, EnumInfo};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Lit, Meta, MetaNameValue};
+
+/// Options given with `#[shift(...)]` on the enum.
+#[derive(Default)]
+pub(crate) struct ShiftOptions {
+    /// The index of the variant that `next()` and `prev()` return instead of `None` past either end,
+    /// given with `overflow_to = "Variant"`.
+    pub overflow_to: Option<usize>,
+    /// Whether the enum implements `Iterator` instead of having an inherent `next()`, given with `iterator`.
+    pub iterator: bool,
+}
+
+pub(crate) fn shift_options(info: &EnumInfo) -> syn::Result<ShiftOptions> {
+    let mut ret = ShiftOptions::default();
+    for option in options(info.attrs, "shift")? {
+        match &option {
+            Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(lit),
+                ..
+            }) if path.is_ident("overflow_to") => ret.overflow_to = Some(info.find_variant(lit)?),
+            Meta::Path(path) if path.is_ident("iterator") => ret.iterator = true,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    option,
+                    "unknown shift option, expected overflow_to = \"Variant\" or iterator",
+                ))
+            }
+        }
+    }
+    Ok(ret)
+}
+
+/// A function from a variant to the next one, as an `Option`, for the generated code that
+/// needs `next()` of a `ShiftEnum`, which is `Iterator::next()` with `#[shift(iterator)]`.
+pub(crate) fn next_fn(info: &EnumInfo) -> TokenStream {
+    let name = info.name;
+    if shift_options(info).map_or(false, |options| options.iterator) {
+        quote! { (|mut variant: #name| ::core::iter::Iterator::next(&mut variant)) }
+    } else {
+        quote! { #name::next }
+    }
+}
+
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<TokenStream> {
+    let name = info.name;
+    let options = shift_options(info)?;
+    let overflow = match options.overflow_to {
+        Some(index) => {
+            let variant = info.variants[index];
+            quote! { .or(::core::option::Option::Some(Self::#variant)) }
+        }
+        None => quote! {},
+    };
+
+    // The variants skipped by `#[rotate(skip_data)]` step to the unit variants declared around them.
+    // They sit between two indices, so the methods that take several steps count from the index
+    // after them going forward and from the one before going backward, which is `start` below.
+    let (next_index, prev_index, start, remaining_forward, remaining_backward, zero) =
+        if info.skipped.is_empty() {
+            (
+                quote! { self.index() + 1 },
+                quote! { self.index().checked_sub(1) },
+                quote! { self.index() as isize },
+                quote! { Self::COUNT - 1 - self.index() },
+                quote! { self.index() },
+                quote! {},
+            )
+        } else {
+            let count = info.variants.len();
+            let skipped = info.skipped_idents();
+            let after = info
+                .skipped
+                .iter()
+                .map(|skipped| skipped.after.unwrap_or(count))
+                .collect::<Vec<_>>();
+            let before = info.skipped.iter().map(|skipped| match skipped.before {
+                Some(index) => quote! { ::core::option::Option::Some(#index) },
+                None => quote! { ::core::option::Option::None },
+            });
+            let (skipped, after) = (&skipped, &after);
+            (
+                quote! {
+                    match self {
+                        #(Self::#skipped { .. } => #after,)*
+                        _ => self.index() + 1,
+                    }
+                },
+                quote! {
+                    match self {
+                        #(Self::#skipped { .. } => #before,)*
+                        _ => self.index().checked_sub(1),
+                    }
+                },
+                quote! {
+                    match self {
+                        #(Self::#skipped { .. } => #after as isize - (n > 0) as isize,)*
+                        _ => self.index() as isize,
+                    }
+                },
+                quote! {
+                    match self {
+                        #(Self::#skipped { .. } => Self::COUNT - #after,)*
+                        _ => Self::COUNT - 1 - self.index(),
+                    }
+                },
+                quote! {
+                    match self {
+                        #(Self::#skipped { .. } => #after,)*
+                        _ => self.index(),
+                    }
+                },
+                quote! {
+                    if n == 0 {
+                        return ::core::option::Option::Some(self);
+                    }
+                },
+            )
+        };
+
+    // With `#[shift(iterator)]`, an inherent `next(self)` would be picked over `Iterator::next(&mut self)`
+    // for `state.next()`, so only the latter is generated, advancing the variant in place.
+    let next = if options.iterator {
+        quote! {
+            #[allow(deprecated)]
+            impl ::core::iter::Iterator for #name {
+                type Item = Self;
+                fn next(&mut self) -> ::core::option::Option<Self> {
+                    let next = Self::from_index(#next_index)#overflow?;
+                    *self = ::core::clone::Clone::clone(&next);
+                    ::core::option::Option::Some(next)
+                }
+            }
+        }
+    } else {
+        quote! {
+            #[allow(deprecated)]
+            impl #name {
+                pub fn next(self) -> ::core::option::Option<Self> {
+                    Self::from_index(#next_index)#overflow
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        #next
+
+        #[allow(deprecated)]
+        impl #name {
+            pub fn prev(self) -> ::core::option::Option<Self> {
+                (#prev_index).and_then(Self::from_index)#overflow
+            }
+            pub fn next_or(self, default: Self) -> Self {
+                Self::from_index(#next_index)#overflow.unwrap_or(default)
+            }
+            pub fn prev_or(self, default: Self) -> Self {
+                self.prev().unwrap_or(default)
+            }
+            pub fn checked_add(self, n: isize) -> ::core::option::Option<Self> {
+                #zero
+                let index = (#start).checked_add(n)?;
+                if index < 0 {
+                    return ::core::option::Option::None;
+                }
+                Self::from_index(index as usize)
+            }
+            pub fn checked_sub(self, n: isize) -> ::core::option::Option<Self> {
+                self.checked_add(n.checked_neg()?)
+            }
+            pub fn saturating_add(self, n: isize) -> Self {
+                self.shift_by(n).0
+            }
+            pub fn saturating_sub(self, n: isize) -> Self {
+                self.saturating_add(n.saturating_neg())
+            }
+            pub fn shift_by(self, n: isize) -> (Self, isize) {
+                let remaining = if n > 0 { #remaining_forward } else { #remaining_backward };
+                let steps = ::core::cmp::Ord::min(n.unsigned_abs(), remaining) as isize * n.signum();
+                (self.checked_add(steps).unwrap(), steps)
+            }
+            pub fn remaining_forward(self) -> usize {
+                #remaining_forward
+            }
+            pub fn remaining_backward(self) -> usize {
+                #remaining_backward
+            }
+        }
+    })
+}
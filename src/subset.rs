@@ -0,0 +1,114 @@
+use crate::{model::EnumInfo, tools::Tools};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    DeriveInput, Ident, Token,
+};
+
+/// The arguments of `#[subset(Cardinal: Up, Down, Left, Right)]`.
+struct Subset {
+    name: Ident,
+    variants: Punctuated<Ident, Token![,]>,
+}
+
+impl Parse for Subset {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let variants = Punctuated::parse_terminated_with(input, Ident::parse_any)?;
+        Ok(Self { name, variants })
+    }
+}
+
+/// Generates an enum for each `#[subset(...)]` attribute on the enum, with the same tools
+/// as the enum itself, and the conversions between them.
+///
+/// The subset is always rotatable unless the enum shifts instead.
+pub(crate) fn generate(
+    info: &EnumInfo,
+    tools: &Tools,
+    macro_name: &str,
+) -> syn::Result<Vec<TokenStream>> {
+    let tools = Tools {
+        rotate: !tools.shift,
+        metadata: false,
+        ..*tools
+    };
+    let name = info.name;
+    let vis = info.vis;
+
+    let mut ret = vec![];
+    for attr in info
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("subset"))
+    {
+        let subset = attr.parse_args::<Subset>()?;
+        for (i, variant) in subset.variants.iter().enumerate() {
+            if !info.variants.iter().any(|v| v.unraw() == variant.unraw()) {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    format!("{} has no variant {}", name, variant),
+                ));
+            }
+            if subset
+                .variants
+                .iter()
+                .take(i)
+                .any(|v| v.unraw() == variant.unraw())
+            {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "this variant is already in the subset",
+                ));
+            }
+        }
+
+        let subset_name = &subset.name;
+        let variants = subset.variants.iter().collect::<Vec<_>>();
+        let item: DeriveInput = syn::parse2(quote! {
+            #[derive(
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::fmt::Debug,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+            )]
+            #vis enum #subset_name {
+                #(#variants, )*
+            }
+        })?;
+        let impls = tools.expand(&item, macro_name)?;
+
+        ret.push(quote! {
+            #item
+            #impls
+
+            #[allow(deprecated)]
+            impl ::core::convert::From<#subset_name> for #name {
+                fn from(value: #subset_name) -> Self {
+                    match value {
+                        #(#subset_name::#variants => Self::#variants, )*
+                    }
+                }
+            }
+
+            #[allow(deprecated)]
+            impl ::core::convert::TryFrom<#name> for #subset_name {
+                type Error = #name;
+                #[allow(unreachable_patterns)]
+                fn try_from(value: #name) -> ::core::result::Result<Self, #name> {
+                    match value {
+                        #(#name::#variants => ::core::result::Result::Ok(Self::#variants), )*
+                        _ => ::core::result::Result::Err(value),
+                    }
+                }
+            }
+        });
+    }
+    Ok(ret)
+}
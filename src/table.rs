@@ -0,0 +1,142 @@
+use crate::{
+    for_each::{define_helper, helper_ident, helper_path},
+    model::EnumInfo,
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+    Path, Token, Type,
+};
+
+/// Generates a fixed-size table with one value per variant if `#[rotate(table)]` is given,
+/// and the helper macro of `enum_table!`, which explains the missing option otherwise.
+pub(crate) fn generate(info: &EnumInfo) -> TokenStream {
+    let helper = helper_ident("table", info.name);
+    if !info.options.table {
+        let message = format!("enum_table! needs #[rotate(table)] on {}", info.name);
+        return define_helper(
+            &helper,
+            quote! {
+                ($($tokens:tt)*) => {
+                    ::core::compile_error!(#message)
+                };
+            },
+        );
+    }
+
+    let name = info.name;
+    let vis = info.type_vis();
+    let count = info.variants.len();
+    let table_name = info.derived_ident("Table");
+
+    let items = quote! {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #vis struct #table_name<T>([T; #count]);
+
+        #[allow(deprecated)]
+        impl<T> #table_name<T> {
+            pub const fn new(values: [T; #count]) -> Self {
+                Self(values)
+            }
+            pub fn get(&self, variant: #name) -> &T {
+                &self.0[variant.index()]
+            }
+            pub fn get_mut(&mut self, variant: #name) -> &mut T {
+                &mut self.0[variant.index()]
+            }
+            pub const fn as_array(&self) -> &[T; #count] {
+                &self.0
+            }
+            pub fn into_array(self) -> [T; #count] {
+                self.0
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<T> ::core::ops::Index<#name> for #table_name<T> {
+            type Output = T;
+            fn index(&self, variant: #name) -> &T {
+                self.get(variant)
+            }
+        }
+
+        #[allow(deprecated)]
+        impl<T> ::core::ops::IndexMut<#name> for #table_name<T> {
+            fn index_mut(&mut self, variant: #name) -> &mut T {
+                self.get_mut(variant)
+            }
+        }
+    };
+
+    // The values are given as the fields of a struct literal, so the compiler reports
+    // a missing, duplicated or unknown variant, and each value is evaluated once, in the order
+    // they are written. The fields are then moved into the array in the order of the variants.
+    // The variants skipped by `#[rotate(skip_data)]` have no slot, so they have no field either.
+    let enum_vis = info.vis;
+    let values_name = info.derived_ident("TableValues");
+    let variants = &info.variants;
+    let bindings = (0..count)
+        .map(|i| format_ident!("value{}", i))
+        .collect::<Vec<_>>();
+    let values = quote! {
+        #[doc(hidden)]
+        #[allow(dead_code, non_snake_case)]
+        #enum_vis struct #values_name<T> {
+            #(pub #variants: T,)*
+        }
+    };
+    let helper_macro = define_helper(
+        &helper,
+        quote! {
+            ([$($prefix:tt)*] $ty:ty; $($entries:tt)*) => {{
+                #[allow(deprecated)]
+                let $($prefix)* #values_name { #(#variants: #bindings),* } =
+                    $($prefix)* #values_name::<$ty> { $($entries)* };
+                $($prefix)* #table_name::<$ty>::new([#(#bindings),*])
+            }};
+        },
+    );
+
+    let items = info.namespace(&table_name, items);
+    quote! {
+        #items
+        #values
+        #helper_macro
+    }
+}
+
+/// The arguments of `enum_table!(Enum => Type { Variant: value, ... })`.
+pub(crate) struct EnumTable {
+    path: Path,
+    ty: Type,
+    entries: TokenStream,
+}
+
+impl Parse for EnumTable {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let ty = input.parse()?;
+        let content;
+        braced!(content in input);
+        Ok(Self {
+            path,
+            ty,
+            entries: content.parse()?,
+        })
+    }
+}
+
+/// Expands `enum_table!` into a call to the helper macro of the enum.
+/// The helper names the generated items by the path of the enum without its last segment.
+pub(crate) fn expand(input: EnumTable) -> TokenStream {
+    let EnumTable { path, ty, entries } = input;
+    let helper = helper_path(&path, "table");
+    let mut prefix = path;
+    prefix.segments.pop();
+    quote! {
+        #helper!([#prefix] #ty; #entries)
+    }
+}
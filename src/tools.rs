@@ -0,0 +1,249 @@
+use crate::{
+    boolean, bounce, category, cfg, default, defmt, description, enum_map, for_each, group, index,
+    iter, kani, key, kind, label, maps_to, model::EnumInfo, names, num_traits, pair, rand, repr,
+    rkyv, rotate, runtime, schemars, self_test, sequence, shift, subset, table, ts_rs, ufmt, value,
+    wasm_bindgen, weight,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Meta, NestedMeta};
+
+/// The set of pieces to generate for an enum.
+///
+/// The standalone derives are fixed sets of these, and `EnumTools` lets the user pick them
+/// with `#[enum_tools(...)]`.
+#[derive(Default, Clone, Copy)]
+pub(crate) struct Tools {
+    pub rotate: bool,
+    pub shift: bool,
+    pub bounce: bool,
+    pub iter: bool,
+    pub count: bool,
+    pub names: bool,
+    /// Whether to read per-variant metadata attributes such as `#[value(...)]`.
+    /// Only the macros that declare or strip these attributes enable it, so that
+    /// the standalone derives never misread another crate's attribute of the same name.
+    pub metadata: bool,
+}
+
+/// Attributes on the enum that configure the generators.
+pub(crate) const CONTAINER_ATTRIBUTES: &[&str] =
+    &["enum_tools", "rotate", "iter", "shift", "subset", "kind"];
+
+/// Attributes on variants that carry metadata or options for the generators.
+/// The derives declare them as helper attributes, and the macros that emit the enum
+/// themselves strip them, along with [`CONTAINER_ATTRIBUTES`], with [`strip_helper_attrs`].
+pub(crate) const VARIANT_ATTRIBUTES: &[&str] = &[
+    "rotate", "name", "value", "key", "pair", "category", "weight", "label",
+];
+
+/// Removes the attributes that only this crate understands, so the enum can be emitted as is.
+pub(crate) fn strip_helper_attrs(input: &mut DeriveInput) {
+    input.attrs.retain(|attr| {
+        !CONTAINER_ATTRIBUTES
+            .iter()
+            .any(|name| attr.path.is_ident(name))
+    });
+    if let Data::Enum(data) = &mut input.data {
+        for variant in &mut data.variants {
+            variant.attrs.retain(|attr| {
+                !VARIANT_ATTRIBUTES
+                    .iter()
+                    .any(|name| attr.path.is_ident(name))
+            });
+        }
+    }
+}
+
+impl Tools {
+    /// The tools that `rotate_enum!` and `#[enumeration]` generate unless told otherwise.
+    pub fn defaults() -> Self {
+        Self {
+            rotate: true,
+            iter: true,
+            count: true,
+            names: true,
+            metadata: true,
+            ..Self::default()
+        }
+    }
+
+    /// Reads the toggles from `#[enum_tools(...)]` attributes.
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut tools = Self {
+            metadata: true,
+            ..Self::default()
+        };
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("enum_tools")) {
+            match attr.parse_meta()? {
+                Meta::List(list) => {
+                    for nested in &list.nested {
+                        tools.set(nested)?;
+                    }
+                }
+                meta => {
+                    return Err(syn::Error::new_spanned(
+                        meta,
+                        "expected a list of tools like #[enum_tools(rotate, iter)]",
+                    ))
+                }
+            }
+        }
+        Ok(tools)
+    }
+
+    /// Like [`Tools::from_attrs`], but falls back to [`Tools::defaults`] without any attribute.
+    pub fn from_attrs_or_defaults(attrs: &[Attribute]) -> syn::Result<Self> {
+        if attrs.iter().any(|attr| attr.path.is_ident("enum_tools")) {
+            Self::from_attrs(attrs)
+        } else {
+            Ok(Self::defaults())
+        }
+    }
+
+    /// Reads the toggles from a list of tools, e.g. the arguments of `#[enumeration(...)]`.
+    pub fn from_nested(nested: &[NestedMeta]) -> syn::Result<Self> {
+        let mut tools = Self {
+            metadata: true,
+            ..Self::default()
+        };
+        for nested in nested {
+            tools.set(nested)?;
+        }
+        Ok(tools)
+    }
+
+    fn set(&mut self, nested: &NestedMeta) -> syn::Result<()> {
+        let flag = match nested {
+            NestedMeta::Meta(Meta::Path(path)) => path.get_ident().and_then(|ident| {
+                Some(match ident.to_string().as_str() {
+                    "rotate" => &mut self.rotate,
+                    "shift" => &mut self.shift,
+                    "bounce" => &mut self.bounce,
+                    "iter" => &mut self.iter,
+                    "count" => &mut self.count,
+                    "names" => &mut self.names,
+                    _ => return None,
+                })
+            }),
+            _ => None,
+        };
+        match flag {
+            Some(flag) => {
+                *flag = true;
+                Ok(())
+            }
+            None => Err(syn::Error::new_spanned(
+                nested,
+                "unknown tool, expected one of rotate, shift, bounce, iter, count or names",
+            )),
+        }
+    }
+
+    pub fn expand(self, input: &DeriveInput, macro_name: &str) -> syn::Result<TokenStream> {
+        let info = EnumInfo::new(input, macro_name)?;
+        let expanded = cfg::apply(&info, self.generate(&info, input, macro_name)?);
+        if info.options.debug {
+            eprintln!(
+                "{} generated for {}:\n{}",
+                macro_name, input.ident, expanded
+            );
+        }
+        Ok(expanded)
+    }
+
+    fn generate(
+        mut self,
+        info: &EnumInfo,
+        input: &DeriveInput,
+        macro_name: &str,
+    ) -> syn::Result<TokenStream> {
+        if self.rotate && self.shift {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "rotate and shift cannot be generated together because both define next() and prev()",
+            ));
+        }
+        self.count |= self.rotate || self.shift || self.bounce || self.names;
+
+        let kind = if self.metadata {
+            kind::generate(info, &self, macro_name)?
+        } else {
+            None
+        };
+        // The tools cannot be generated for an enum with data, so they only go to its kind.
+        if info.carries_data() {
+            if let Some(kind) = kind {
+                return Ok(kind);
+            }
+        }
+
+        let pieces = [
+            (
+                self.count,
+                index::generate as fn(&EnumInfo) -> syn::Result<TokenStream>,
+            ),
+            (self.rotate, rotate::generate),
+            (self.shift, shift::generate),
+            (self.bounce, bounce::generate),
+            (self.iter, iter::generate),
+            (self.names, names::generate),
+        ];
+        let mut pieces = pieces
+            .iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, generate)| generate(info))
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        if self.rotate {
+            pieces.extend(sequence::generate(info));
+            pieces.extend(group::generate(info)?);
+        }
+
+        if self.count {
+            pieces.push(table::generate(info));
+            pieces.extend(default::generate(info)?);
+            pieces.extend(description::generate(info));
+            pieces.extend(boolean::generate(info)?);
+            pieces.extend(maps_to::generate(info)?);
+            pieces.extend(repr::generate(info)?);
+            pieces.push(for_each::generate(info));
+            pieces.extend(runtime::generate(info, &self));
+            pieces.extend(defmt::generate(info));
+            pieces.extend(ufmt::generate(info));
+            pieces.extend(rkyv::generate(info));
+            pieces.extend(schemars::generate(info));
+            pieces.extend(num_traits::generate(info));
+            pieces.extend(enum_map::generate(info));
+            pieces.extend(ts_rs::generate(info));
+            pieces.extend(wasm_bindgen::generate(info, &self));
+            pieces.extend(rand::generate(info, &self));
+        }
+
+        if self.metadata {
+            pieces.extend(value::generate(info)?);
+            pieces.extend(key::generate(info)?);
+            pieces.extend(pair::generate(info)?);
+            pieces.extend(category::generate(info)?);
+            pieces.extend(weight::generate(info)?);
+            pieces.extend(label::generate(info)?);
+            pieces.extend(subset::generate(info, &self, macro_name)?);
+            pieces.extend(kind);
+        }
+
+        if pieces.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                format!(
+                    "{} needs at least one tool, e.g. #[enum_tools(rotate, iter)]",
+                    macro_name
+                ),
+            ));
+        }
+
+        pieces.extend(self_test::generate(info, &self));
+        pieces.extend(kani::generate(info, &self));
+
+        Ok(quote! { #(#pieces)* })
+    }
+}
@@ -0,0 +1,48 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+
+/// Implements ts-rs' `TS` if the enum has `#[rotate(ts_rs)]`.
+///
+/// The type is a union of the variant names as string literals, like the schema of the `schemars` option.
+pub(crate) fn generate(info: &EnumInfo) -> Option<TokenStream> {
+    if !info.options.ts_rs {
+        return None;
+    }
+
+    let name = info.name;
+    let type_name = name.unraw().to_string();
+    let names = info.variant_names();
+    let union = if names.is_empty() {
+        "never".to_string()
+    } else {
+        names
+            .iter()
+            .map(|name| format!("{:?}", name))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    };
+    let decl = format!("type {} = {};", type_name, union);
+
+    Some(quote! {
+        #[allow(deprecated)]
+        impl ::ts_rs::TS for #name {
+            fn name() -> ::std::string::String {
+                ::std::borrow::ToOwned::to_owned(#type_name)
+            }
+            fn decl() -> ::std::string::String {
+                ::std::borrow::ToOwned::to_owned(#decl)
+            }
+            fn inline() -> ::std::string::String {
+                ::std::borrow::ToOwned::to_owned(#union)
+            }
+            fn dependencies() -> ::std::vec::Vec<::ts_rs::Dependency> {
+                ::std::vec::Vec::new()
+            }
+            fn transparent() -> bool {
+                false
+            }
+        }
+    })
+}
@@ -0,0 +1,31 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Implements `ufmt::uDisplay` with the variant names if the enum has `#[rotate(ufmt)]`.
+pub(crate) fn generate(info: &EnumInfo) -> Option<TokenStream> {
+    if !info.options.ufmt {
+        return None;
+    }
+
+    let name = info.name;
+    let skipped = info.skipped_idents();
+    let skipped_names = info.skipped_names();
+    let variants = &info.variants;
+    let names = info.variant_names();
+
+    Some(quote! {
+        #[allow(deprecated)]
+        impl ::ufmt::uDisplay for #name {
+            fn fmt<W>(&self, f: &mut ::ufmt::Formatter<'_, W>) -> ::core::result::Result<(), W::Error>
+            where
+                W: ::ufmt::uWrite + ?::core::marker::Sized,
+            {
+                match *self {
+                    #(Self::#variants => f.write_str(#names), )*
+                    #(Self::#skipped { .. } => f.write_str(#skipped_names), )*
+                }
+            }
+        }
+    })
+}
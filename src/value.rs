@@ -0,0 +1,32 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Expr;
+
+/// Generates `value()` and `from_value()` if the variants have `#[value(...)]` attributes.
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<Option<TokenStream>> {
+    let values = match info.variant_args::<Expr>("value")? {
+        Some(values) => values,
+        None => return Ok(None),
+    };
+    let name = info.name;
+    let patterns = info.variant_patterns(&quote! { Self });
+    let variants = &info.variants;
+
+    Ok(Some(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            pub fn value(&self) -> f64 {
+                match *self {
+                    #(#patterns => #values, )*
+                }
+            }
+            pub fn from_value(value: f64) -> ::core::option::Option<Self> {
+                #(if value == (#values) {
+                    return ::core::option::Option::Some(Self::#variants);
+                })*
+                ::core::option::Option::None
+            }
+        }
+    }))
+}
@@ -0,0 +1,76 @@
+use crate::{
+    model::{snake_case, EnumInfo},
+    shift,
+    tools::Tools,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, Ident};
+
+/// Generates free functions exported with `#[wasm_bindgen]` if `#[rotate(wasm_bindgen)]` is given,
+/// e.g. `direction_next(value)` for `Direction::next()`.
+///
+/// wasm-bindgen only exports methods of structs, so the generated methods are wrapped instead.
+/// The enum itself needs `#[wasm_bindgen]` to be passed to and from JavaScript.
+pub(crate) fn generate(info: &EnumInfo, tools: &Tools) -> Option<TokenStream> {
+    if !info.options.wasm_bindgen {
+        return None;
+    }
+
+    let name = info.name;
+    // The functions are as visible as the enum, which they take and return. Private ones are only
+    // called from JavaScript, so they are dead code on other targets.
+    let vis = info.vis;
+    let prefix = snake_case(&name.unraw().to_string());
+    let function = |suffix: &str| Ident::new(&format!("{}_{}", prefix, suffix), name.span());
+
+    let mut functions = vec![];
+    if tools.count {
+        let (count, index, from_index) =
+            (function("count"), function("index"), function("from_index"));
+        functions.push(quote! {
+            #vis fn #count() -> usize {
+                #name::COUNT
+            }
+        });
+        functions.push(quote! {
+            #vis fn #index(value: #name) -> usize {
+                value.index()
+            }
+        });
+        functions.push(quote! {
+            #vis fn #from_index(index: usize) -> ::core::option::Option<#name> {
+                #name::from_index(index)
+            }
+        });
+    }
+    if tools.rotate || tools.shift {
+        let (next, prev) = (function("next"), function("prev"));
+        let (output, next_fn) = if tools.rotate {
+            (quote! { #name }, quote! { #name::next })
+        } else {
+            (
+                quote! { ::core::option::Option<#name> },
+                shift::next_fn(info),
+            )
+        };
+        functions.push(quote! {
+            #vis fn #next(value: #name) -> #output {
+                #next_fn(value)
+            }
+        });
+        functions.push(quote! {
+            #vis fn #prev(value: #name) -> #output {
+                value.prev()
+            }
+        });
+    }
+
+    Some(quote! {
+        #(
+            #[::wasm_bindgen::prelude::wasm_bindgen]
+            #[allow(deprecated, dead_code)]
+            #functions
+        )*
+    })
+}
@@ -0,0 +1,58 @@
+use crate::model::EnumInfo;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitInt;
+
+/// Generates `weight()` if the variants have `#[weight(...)]` attributes,
+/// and `random_weighted()` that picks a variant with a probability proportional to it
+/// if the enum has `#[rotate(rand)]`.
+pub(crate) fn generate(info: &EnumInfo) -> syn::Result<Option<TokenStream>> {
+    let weights = match info.variant_args::<LitInt>("weight")? {
+        Some(weights) => weights,
+        None => return Ok(None),
+    };
+    // The variant is picked by the first bound that is larger than a number below the total.
+    let mut bounds = vec![];
+    let mut total = 0u32;
+    for weight in &weights {
+        total = total
+            .checked_add(weight.base10_parse()?)
+            .ok_or_else(|| syn::Error::new_spanned(weight, "the total weight overflows u32"))?;
+        bounds.push(total);
+    }
+    if total == 0 {
+        return Err(syn::Error::new_spanned(
+            &weights[0],
+            "at least one variant needs a positive weight",
+        ));
+    }
+    let name = info.name;
+    let patterns = info.variant_patterns(&quote! { Self });
+    let variants = &info.variants;
+
+    let random_weighted = if info.options.rand {
+        quote! {
+            pub fn random_weighted(rng: &mut impl ::rand::Rng) -> Self {
+                let pick = ::rand::Rng::gen_range(rng, 0..#total);
+                #(if pick < #bounds {
+                    return Self::#variants;
+                })*
+                ::core::unreachable!()
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(Some(quote! {
+        #[allow(deprecated)]
+        impl #name {
+            pub fn weight(&self) -> u32 {
+                match *self {
+                    #(#patterns => #weights, )*
+                }
+            }
+            #random_weighted
+        }
+    }))
+}
@@ -0,0 +1,28 @@
+use rotate_enum::{EnumTools, RotateEnum};
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(aliases = "cw_ccw")]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate)]
+#[rotate(aliases = "forward_backward")]
+enum Gear {
+    First,
+    Second,
+    Third,
+}
+
+#[test]
+fn test_aliases() {
+    assert_eq!(Direction::Up.rotate_cw(), Direction::Right);
+    assert_eq!(Direction::Up.rotate_ccw(), Direction::Left);
+    assert_eq!(Direction::Left.rotate_cw(), Direction::Up);
+    assert_eq!(Gear::Third.rotate_forward(), Gear::First);
+    assert_eq!(Gear::Second.rotate_backward(), Gear::First);
+}
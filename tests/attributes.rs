@@ -0,0 +1,87 @@
+#![deny(deprecated)]
+
+use rotate_enum::{for_each_variant, EnumTools, IterEnum, RotateEnum, ShiftEnum};
+
+#[derive(RotateEnum, IterEnum, PartialEq, Clone, Copy, Debug)]
+#[repr(u8)]
+enum Direction {
+    /// Towards the top of the screen.
+    Up = 1,
+    #[allow(dead_code)]
+    Left = 2,
+    /// Towards the bottom of the screen.
+    #[doc(alias = "South")]
+    Down = 4,
+    Right = 8,
+}
+
+#[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+enum Step {
+    /// The first step.
+    First = 10,
+    #[allow(unused)]
+    Second = 20,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate, names)]
+enum Axis {
+    /// Up and down.
+    #[value(0.0)]
+    Vertical = 3,
+    /// Left and right.
+    #[value(90.0)]
+    Horizontal = 7,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate, iter, names)]
+#[rotate(table, default, self_test)]
+enum Mode {
+    #[deprecated(note = "use Fast instead")]
+    #[value(0.5)]
+    #[key('s')]
+    Slow,
+    #[value(1.0)]
+    #[key('f')]
+    Fast,
+}
+
+#[deprecated]
+#[derive(ShiftEnum, IterEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(self_test)]
+enum Legacy {
+    First,
+    Second,
+}
+
+#[test]
+fn test_attributes() {
+    assert_eq!(Direction::Up.next(), Direction::Left);
+    assert_eq!(Direction::Up.prev(), Direction::Right);
+    assert_eq!(Direction::Down.index(), 2);
+    assert_eq!(Direction::Down as u8, 4);
+    assert_eq!(Direction::Left.iter().count(), 3);
+
+    assert_eq!(Step::First.next(), Some(Step::Second));
+    assert_eq!(Step::Second.next(), None);
+
+    assert_eq!(Axis::Vertical.next(), Axis::Horizontal);
+    assert_eq!(Axis::Horizontal.name(), "Horizontal");
+    assert_eq!(Axis::Horizontal.value(), 90.0);
+}
+
+#[test]
+fn test_deprecated() {
+    assert_eq!(Mode::Fast.next().name(), "Slow");
+    assert_eq!(Mode::Fast.iter().count(), 1);
+    assert_eq!(Mode::from_value(1.0), Some(Mode::Fast));
+    let mut names = vec![];
+    for_each_variant!(Mode, |mode| names.push(mode.name()));
+    assert_eq!(names, ["Slow", "Fast"]);
+
+    #[allow(deprecated)]
+    {
+        assert_eq!(Legacy::First.next(), Some(Legacy::Second));
+    }
+}
@@ -0,0 +1,52 @@
+use rotate_enum::{EnumTools, RotateEnum, ShiftEnum};
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+enum Switch {
+    Off,
+    On,
+}
+
+#[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(true_variant = "Enabled")]
+enum Feature {
+    Enabled,
+    Disabled,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(count)]
+enum Mode {
+    Light,
+    Dark,
+}
+
+#[test]
+fn test_from_bool() {
+    assert_eq!(Switch::from(false), Switch::Off);
+    assert_eq!(Switch::from(true), Switch::On);
+    assert_eq!(Feature::from(true), Feature::Enabled);
+    assert_eq!(Feature::from(false), Feature::Disabled);
+    let mode: Mode = true.into();
+    assert_eq!(mode, Mode::Dark);
+    assert_eq!(Mode::from(false), Mode::Light);
+}
+
+#[test]
+fn test_into_bool() {
+    assert!(bool::from(Switch::On));
+    assert!(!bool::from(Switch::Off));
+    assert!(bool::from(Feature::Enabled));
+    let disabled: bool = Feature::Disabled.into();
+    assert!(!disabled);
+    assert!(bool::from(Switch::Off.toggle()));
+    assert!(bool::from(Mode::Dark));
+}
+
+#[test]
+fn test_not() {
+    assert_eq!(!Switch::On, Switch::Off);
+    assert_eq!(!Switch::Off, Switch::On);
+    assert_eq!(!Feature::Enabled, Feature::Disabled);
+    assert_eq!(!!Mode::Dark, Mode::Dark);
+    assert_eq!(bool::from(!Mode::Light), !bool::from(Mode::Light));
+}
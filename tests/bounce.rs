@@ -0,0 +1,64 @@
+use rotate_enum::{BounceEnum, EnumTools};
+
+#[derive(BounceEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(self_test)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(BounceEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(self_test)]
+enum Single {
+    Only,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate, bounce)]
+enum Switch {
+    On,
+    Off,
+}
+
+#[derive(BounceEnum, PartialEq, Debug)]
+enum Owned {
+    First,
+    Second,
+    Third,
+}
+
+#[test]
+fn test_bounce() {
+    assert_eq!(Direction::Up.bounce(true), (Direction::Left, true));
+    assert_eq!(Direction::Right.bounce(true), (Direction::Down, false));
+    assert_eq!(Direction::Left.bounce(false), (Direction::Up, false));
+    assert_eq!(Direction::Up.bounce(false), (Direction::Left, true));
+    assert_eq!(Single::Only.bounce(true), (Single::Only, true));
+    assert_eq!(Single::Only.bounce(false), (Single::Only, false));
+    assert_eq!(Switch::On.bounce(false), (Switch::Off, true));
+    assert_eq!(Switch::On.next(), Switch::Off);
+    assert_eq!(Owned::Third.bounce(true), (Owned::Second, false));
+}
+
+#[test]
+fn test_bounce_iter() {
+    let frames = Direction::Up.bounce_iter(true).take(7).collect::<Vec<_>>();
+    assert_eq!(
+        frames,
+        [
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+            Direction::Up,
+            Direction::Left,
+        ]
+    );
+    let frames = Switch::Off.bounce_iter(true).take(3).collect::<Vec<_>>();
+    assert_eq!(frames, [Switch::On, Switch::Off, Switch::On]);
+    let frames = Owned::First.bounce_iter(false).take(2).collect::<Vec<_>>();
+    assert_eq!(frames, [Owned::Second, Owned::Third]);
+}
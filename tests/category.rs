@@ -0,0 +1,51 @@
+use rotate_enum::{enumeration, EnumTools};
+
+mod kind {
+    #[derive(PartialEq, Debug)]
+    pub enum Axis {
+        Horizontal,
+        Vertical,
+    }
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate)]
+enum Direction {
+    #[category(kind::Axis::Vertical)]
+    Up,
+    #[category(kind::Axis::Horizontal)]
+    Left,
+    #[category(kind::Axis::Vertical)]
+    Down,
+    #[category(kind::Axis::Horizontal)]
+    Right,
+}
+
+#[derive(PartialEq, Debug)]
+enum Color {
+    Black,
+    White,
+}
+
+#[enumeration(count)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Piece {
+    #[category(Color::White)]
+    WhiteKing,
+    #[category(Color::Black)]
+    BlackKing,
+}
+
+#[test]
+fn test_category() {
+    assert_eq!(Direction::Up.category(), kind::Axis::Vertical);
+    assert_eq!(Direction::Left.category(), kind::Axis::Horizontal);
+    assert_eq!(Direction::Left.next().category(), kind::Axis::Vertical);
+    assert_eq!(Direction::Right.category(), kind::Axis::Horizontal);
+
+    assert_eq!(Piece::WhiteKing.category(), Color::White);
+    assert_eq!(
+        Piece::from_index(1).map(|p| p.category()),
+        Some(Color::Black)
+    );
+}
@@ -0,0 +1,47 @@
+use rotate_enum::{EnumTools, RotateEnum};
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(cfg(any()))]
+enum Gated {
+    On,
+    Off,
+}
+
+// The generated methods are compiled out, so these don't conflict with them.
+impl Gated {
+    fn next(self) -> Self {
+        self
+    }
+}
+
+impl From<bool> for Gated {
+    fn from(on: bool) -> Self {
+        if on {
+            Gated::On
+        } else {
+            Gated::Off
+        }
+    }
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate, iter, names)]
+#[rotate(cfg(all()), cfg_attr(all(), doc(hidden)), table, hidden_module)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[test]
+fn test_cfg() {
+    assert_eq!(Gated::On.next(), Gated::On);
+    assert_eq!(Gated::from(false), Gated::Off);
+
+    assert_eq!(Direction::Up.next(), Direction::Left);
+    assert_eq!(Direction::Left.iter().count(), 3);
+    assert_eq!(Direction::Down.name(), "Down");
+    let table = DirectionTable::new([1, 2, 3, 4]);
+    assert_eq!(table[Direction::Right], 4);
+}
@@ -0,0 +1,39 @@
+//! `#[rotate(debug)]` only prints the generated code, so the enum gets the same API as without it.
+
+macro_rules! direction {
+    ($($option:ident)?) => {
+        use rotate_enum::EnumTools;
+
+        #[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+        #[enum_tools(rotate, iter, names)]
+        #[rotate(table $(, $option)?)]
+        pub enum Direction {
+            Up,
+            Left,
+            Down,
+            Right,
+        }
+    };
+}
+
+mod plain {
+    direction!();
+}
+
+mod debug {
+    direction!(debug);
+}
+
+#[test]
+fn test_debug() {
+    assert_eq!(plain::Direction::COUNT, debug::Direction::COUNT);
+    for (plain, debug) in plain::Direction::Up.iter().zip(debug::Direction::Up.iter()) {
+        assert_eq!(plain.index(), debug.index());
+        assert_eq!(plain.next().index(), debug.next().index());
+        assert_eq!(plain.prev().index(), debug.prev().index());
+        assert_eq!(plain.name(), debug.name());
+    }
+    let plain = plain::DirectionTable::new([1, 2, 3, 4]);
+    let debug = debug::DirectionTable::new([1, 2, 3, 4]);
+    assert_eq!(plain[plain::Direction::Down], debug[debug::Direction::Down]);
+}
@@ -0,0 +1,36 @@
+use rotate_enum::{EnumTools, RotateEnum, ShiftEnum};
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(default)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(default = "Medium")]
+enum Speed {
+    Slow,
+    Medium,
+    Fast,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(count)]
+#[rotate(default = "Stop")]
+enum Signal {
+    Go,
+    Stop,
+}
+
+#[test]
+fn test_default() {
+    assert_eq!(Direction::default(), Direction::Up);
+    assert_eq!(Direction::default().prev(), Direction::Right);
+    assert_eq!(Speed::default(), Speed::Medium);
+    assert_eq!(Speed::default().next(), Some(Speed::Fast));
+    assert_eq!(Signal::default(), Signal::Stop);
+    assert_eq!(Signal::from_index(0), Some(Signal::Go));
+}
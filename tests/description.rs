@@ -0,0 +1,43 @@
+use rotate_enum::{enumeration, RotateEnum};
+
+#[derive(RotateEnum, Clone, Copy, PartialEq, Debug)]
+#[rotate(description)]
+enum Tool {
+    /// Draws freehand lines.
+    Pen,
+    /// Removes what was drawn.
+    ///
+    /// Hold shift to erase everything.
+    Eraser,
+    /** Selects an area. */
+    Select,
+    #[doc = "Fills an area."]
+    Fill,
+    Zoom,
+}
+
+#[enumeration(shift)]
+#[rotate(description)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Level {
+    ///   Indented
+    Low,
+    /// High
+    High,
+}
+
+#[test]
+fn test_description() {
+    assert_eq!(Tool::Pen.description(), "Draws freehand lines.");
+    assert_eq!(
+        Tool::Eraser.description(),
+        "Removes what was drawn.\n\nHold shift to erase everything."
+    );
+    assert_eq!(Tool::Select.description(), "Selects an area.");
+    assert_eq!(Tool::Fill.description(), "Fills an area.");
+    assert_eq!(Tool::Zoom.description(), "");
+    assert_eq!(Tool::Zoom.next().description(), Tool::Pen.description());
+
+    assert_eq!(Level::Low.description(), "Indented");
+    assert_eq!(Level::Low.next().map(|l| l.description()), Some("High"));
+}
@@ -0,0 +1,30 @@
+use rotate_enum::enumeration;
+
+#[enumeration(shift, iter)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[iter(start = "Left")]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[enumeration]
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+#[test]
+fn test_enumeration() {
+    assert_eq!(Direction::Up.next(), Some(Direction::Left));
+    assert_eq!(Direction::Up.prev(), None);
+    assert_eq!(Direction::Down.iter().count(), 2);
+    assert_eq!(DirectionIterator::new().next(), Some(Direction::Left));
+    assert_eq!(Direction::COUNT, 4);
+
+    assert_eq!(Axis::Horizontal.next(), Axis::Vertical);
+    assert_eq!(Axis::Vertical.name(), "Vertical");
+}
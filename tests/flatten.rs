@@ -0,0 +1,93 @@
+use rotate_enum::IterEnum;
+
+mod items {
+    use rotate_enum::IterEnum;
+
+    #[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+    pub enum File {
+        New,
+        Open,
+        Save,
+    }
+}
+
+#[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+enum Edit {
+    Undo,
+    Redo,
+}
+
+#[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+#[iter(flatten)]
+enum Menu {
+    About,
+    File(items::File),
+    Edit(Edit),
+    Quit,
+}
+
+#[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+#[iter(flatten)]
+enum Nested {
+    Menu(Menu),
+}
+
+// A raw identifier keeps its name without `r#` in the derived iterator, `typeIterator`.
+#[allow(non_camel_case_types)]
+mod raw {
+    use rotate_enum::IterEnum;
+
+    #[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+    pub enum r#type {
+        Int,
+        Float,
+    }
+
+    #[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+    #[iter(flatten)]
+    pub enum Declaration {
+        Type(r#type),
+        Void,
+    }
+}
+
+#[test]
+fn test_flatten() {
+    let mut iter = MenuIterator::new();
+    assert_eq!(iter.len(), 7);
+    assert_eq!(iter.next(), Some(Menu::About));
+    assert_eq!(iter.len(), 6);
+    assert_eq!(
+        iter.by_ref().take(4).collect::<Vec<_>>(),
+        vec![
+            Menu::File(items::File::New),
+            Menu::File(items::File::Open),
+            Menu::File(items::File::Save),
+            Menu::Edit(Edit::Undo),
+        ]
+    );
+    assert_eq!(iter.len(), 2);
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        vec![Menu::Edit(Edit::Redo), Menu::Quit]
+    );
+
+    assert_eq!(NestedIterator::new().len(), 7);
+    assert_eq!(
+        NestedIterator::default().last(),
+        Some(Nested::Menu(Menu::Quit))
+    );
+}
+
+#[test]
+fn test_flatten_raw() {
+    use raw::{r#type, Declaration};
+    assert_eq!(
+        raw::DeclarationIterator::new().collect::<Vec<_>>(),
+        vec![
+            Declaration::Type(r#type::Int),
+            Declaration::Type(r#type::Float),
+            Declaration::Void,
+        ]
+    );
+}
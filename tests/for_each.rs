@@ -0,0 +1,112 @@
+use rotate_enum::{enumeration, for_each_variant, RotateEnum, ShiftEnum};
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+enum Speed {
+    Slow,
+    Fast,
+}
+
+#[enumeration(count, names)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum r#Season {
+    Spring,
+    r#Summer,
+}
+
+#[test]
+fn test_for_each_variant() {
+    let mut visited = vec![];
+    for_each_variant!(Direction, |direction| visited.push(direction));
+    assert_eq!(
+        visited,
+        [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right
+        ]
+    );
+}
+
+#[test]
+fn test_for_each_variant_const() {
+    let mut indices = [0; 4];
+    for_each_variant!(self::Direction, |direction| {
+        const INDEX: usize = direction as usize;
+        indices[INDEX] += 1;
+    });
+    assert_eq!(indices, [1; 4]);
+}
+
+#[test]
+fn test_for_each_variant_shift() {
+    let mut next = vec![];
+    for_each_variant!(Speed, |speed| next.push(speed.next()));
+    assert_eq!(next, [Some(Speed::Fast), None]);
+}
+
+#[test]
+fn test_for_each_variant_raw() {
+    let mut names = vec![];
+    for_each_variant!(Season, |season| names.push(season.name()));
+    assert_eq!(names, ["Spring", "Summer"]);
+}
+
+mod compass {
+    use rotate_enum::RotateEnum;
+
+    // Shares the name with the enum at the top, but has other variants.
+    #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+    pub enum Direction {
+        North,
+        East,
+        South,
+        West,
+    }
+
+    pub mod child {
+        use rotate_enum::for_each_variant;
+
+        pub fn names() -> Vec<&'static str> {
+            let mut names = vec![];
+            for_each_variant!(super::Direction, |direction| {
+                fn name(variant: super::Direction) -> &'static str {
+                    match variant {
+                        super::Direction::North => "north",
+                        super::Direction::East => "east",
+                        super::Direction::South => "south",
+                        super::Direction::West => "west",
+                    }
+                }
+                names.push(name(direction));
+            });
+            names
+        }
+    }
+}
+
+mod sibling {
+    use rotate_enum::for_each_variant;
+
+    pub fn visit() -> Vec<crate::compass::Direction> {
+        let mut visited = vec![];
+        for_each_variant!(crate::compass::Direction, |direction| visited
+            .push(direction));
+        visited
+    }
+}
+
+#[test]
+fn test_for_each_variant_path() {
+    use compass::Direction::*;
+    assert_eq!(sibling::visit(), [North, East, South, West]);
+    assert_eq!(compass::child::names(), ["north", "east", "south", "west"]);
+}
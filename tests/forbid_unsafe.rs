@@ -0,0 +1,62 @@
+//! The generated code must not need `unsafe`, so that crates with `#![forbid(unsafe_code)]`
+//! can use every derive and option.
+#![forbid(unsafe_code)]
+
+use rotate_enum::{BounceEnum, EnumTools, IterEnum, RotateEnum, ShiftEnum};
+use std::convert::TryFrom;
+
+#[derive(RotateEnum, IterEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(
+    table,
+    default,
+    description,
+    maps_to = "Heading",
+    repr_conversions,
+    self_test
+)]
+#[repr(u8)]
+enum Direction {
+    Up,
+    Left,
+    #[rotate(self_loop)]
+    Down,
+    Right,
+}
+
+#[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+#[shift(overflow_to = "Fast")]
+enum Speed {
+    Slow,
+    Fast,
+}
+
+#[derive(BounceEnum, PartialEq, Clone, Copy, Debug)]
+enum Level {
+    Low,
+    High,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate, iter, names)]
+#[subset(Vertical: North, South)]
+enum Heading {
+    #[value(0.0)]
+    North,
+    #[value(90.0)]
+    East,
+    #[value(180.0)]
+    South,
+    #[value(270.0)]
+    West,
+}
+
+#[test]
+fn test_forbid_unsafe() {
+    assert_eq!(Heading::from(Direction::Left), Heading::East);
+    assert_eq!(Direction::try_from(3u8), Ok(Direction::Right));
+    assert_eq!(Direction::Down.next(), Direction::Down);
+    assert_eq!(Speed::Fast.next(), Some(Speed::Fast));
+    assert_eq!(Level::High.bounce(true), (Level::Low, false));
+    assert_eq!(Vertical::North.next(), Vertical::South);
+    assert_eq!("West".parse(), Ok(Heading::West));
+}
@@ -0,0 +1,40 @@
+use rotate_enum::{EnumTools, RotateEnum};
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(group)]
+enum Hex {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate)]
+#[rotate(group)]
+enum Single {
+    Only,
+}
+
+#[test]
+fn test_group() {
+    assert_eq!(Hex::IDENTITY, Hex::East);
+    assert_eq!(Hex::NorthEast + Hex::NorthWest, Hex::West);
+    assert_eq!(Hex::SouthWest + Hex::West, Hex::NorthEast);
+    assert_eq!(Hex::NorthEast.inverse(), Hex::SouthEast);
+    assert_eq!(Hex::West.inverse(), Hex::West);
+    assert_eq!(Hex::East.inverse(), Hex::East);
+    for a in (0..Hex::COUNT).map(Hex::from_index_wrapping) {
+        assert_eq!(a + Hex::IDENTITY, a);
+        assert_eq!(a + a.inverse(), Hex::IDENTITY);
+        for b in (0..Hex::COUNT).map(Hex::from_index_wrapping) {
+            assert_eq!(a + b, b + a);
+            assert_eq!(a + b, a.wrapping_add(b.index()));
+        }
+    }
+
+    assert_eq!(Single::Only + Single::Only, Single::IDENTITY);
+    assert_eq!(Single::Only.inverse(), Single::Only);
+}
@@ -0,0 +1,108 @@
+use rotate_enum::{EnumTools, IterEnum, RotateEnum};
+
+#[derive(RotateEnum, IterEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(hidden_module, table)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+mod items {
+    use rotate_enum::IterEnum;
+
+    #[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+    #[rotate(hidden_module)]
+    pub enum File {
+        New,
+        Open,
+    }
+
+    pub mod nested {
+        use rotate_enum::EnumTools;
+
+        #[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+        #[enum_tools(shift, iter, names)]
+        #[rotate(hidden_module)]
+        pub(in crate::items) enum Speed {
+            Slow,
+            Fast,
+        }
+
+        #[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+        #[enum_tools(iter, names)]
+        #[rotate(hidden_module)]
+        pub(super) enum Edit {
+            Undo,
+            Redo,
+        }
+    }
+
+    #[test]
+    fn test_restricted() {
+        use nested::{Edit, EditIterator, EditParseError, Speed, SpeedIterator};
+        let speeds: SpeedIterator = Speed::Slow.iter();
+        assert_eq!(speeds.collect::<Vec<_>>(), [Speed::Slow, Speed::Fast]);
+        let edits: EditIterator = Edit::Redo.iter();
+        assert_eq!(edits.len(), 1);
+        assert_eq!("Undo".parse(), Ok(Edit::Undo));
+        let error: EditParseError = "Cut".parse::<Edit>().unwrap_err();
+        assert_eq!(error.input(), "Cut");
+    }
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(iter)]
+#[iter(flatten)]
+#[rotate(hidden_module)]
+pub(crate) enum Menu {
+    About,
+    File(items::File),
+}
+
+#[test]
+fn test_hidden_module() {
+    let iter: DirectionIterator = Direction::Down.iter();
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        [Direction::Down, Direction::Right]
+    );
+    let table = DirectionTable::new([1, 2, 3, 4]);
+    assert_eq!(table[Direction::Up.prev()], 4);
+
+    let files: items::FileIterator = items::File::New.iter();
+    assert_eq!(files.count(), 2);
+
+    let menu = MenuIterator::new().collect::<Vec<_>>();
+    assert_eq!(
+        menu,
+        [
+            Menu::About,
+            Menu::File(items::File::New),
+            Menu::File(items::File::Open)
+        ]
+    );
+}
+
+mod shadowed {
+    use rotate_enum::IterEnum;
+
+    #[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+    #[rotate(hidden_module)]
+    pub enum Direction {
+        Up,
+        Down,
+    }
+
+    // Declaring a type with the name of a generated one is not an error,
+    // and the generated type is still reachable through its module.
+    pub struct DirectionIterator;
+
+    #[test]
+    fn test_shadowed() {
+        let _: DirectionIterator = DirectionIterator;
+        let iter: __rotate_enum_direction_iterator::DirectionIterator = Direction::Up.iter();
+        assert_eq!(iter.len(), 2);
+    }
+}
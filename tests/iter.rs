@@ -9,6 +9,7 @@ enum Direction {
 }
 
 #[test]
+#[allow(clippy::partialeq_to_none)]
 fn test_shift() {
     let up = Direction::Up;
     let left = Direction::Left;
@@ -36,3 +37,138 @@ fn test_shift() {
         ]
     );
 }
+
+#[test]
+fn test_iter_indexed() {
+    assert_eq!(
+        Direction::Up.iter_indexed().collect::<Vec<_>>(),
+        vec![
+            (0, Direction::Up),
+            (1, Direction::Left),
+            (2, Direction::Down),
+            (3, Direction::Right),
+        ]
+    );
+    assert_eq!(
+        Direction::Right.iter_indexed().collect::<Vec<_>>(),
+        vec![(3, Direction::Right)]
+    );
+}
+
+#[test]
+fn test_remaining() {
+    let mut iter = Direction::Up.iter();
+    assert_eq!(iter.remaining(), 4);
+    assert_eq!(iter.len(), 4);
+    iter.next();
+    assert_eq!(iter.remaining(), 3);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    iter.by_ref().for_each(drop);
+    assert_eq!(iter.remaining(), 0);
+    assert!(iter.next().is_none());
+    assert_eq!(iter.len(), 0);
+
+    assert_eq!(DirectionIterator::new().len(), 4);
+}
+
+#[derive(Default)]
+struct Sweep {
+    directions: DirectionIterator,
+}
+
+#[test]
+fn test_default() {
+    let mut sweep = Sweep::default();
+    assert_eq!(sweep.directions.next(), Some(Direction::Up));
+    assert_eq!(DirectionIterator::default().count(), 4);
+}
+
+fn collect_from<'a, T>(start: &'a T) -> Vec<T>
+where
+    &'a T: IntoIterator<Item = T>,
+{
+    start.into_iter().collect()
+}
+
+#[test]
+fn test_into_iter() {
+    let mut visited = vec![];
+    for direction in &Direction::Left {
+        visited.push(direction);
+    }
+    assert_eq!(
+        visited,
+        vec![Direction::Left, Direction::Down, Direction::Right]
+    );
+
+    assert_eq!(
+        collect_from(&Direction::Down),
+        vec![Direction::Down, Direction::Right]
+    );
+}
+
+pub mod public {
+    use rotate_enum::IterEnum;
+
+    #[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+    pub enum Axis {
+        Vertical,
+        Horizontal,
+    }
+}
+
+#[test]
+fn test_public() {
+    let iter: public::AxisIterator = (&public::Axis::Vertical).into_iter();
+    assert_eq!(iter.len(), 2);
+}
+
+#[derive(IterEnum, PartialEq, Debug)]
+enum Resource {
+    Wood,
+    Stone,
+    Iron,
+}
+
+#[test]
+fn test_iter_refs() {
+    let refs: Vec<&'static Resource> = Resource::Wood.iter_refs().collect();
+    assert_eq!(
+        refs,
+        vec![&Resource::Wood, &Resource::Stone, &Resource::Iron]
+    );
+    assert_eq!(Resource::Iron.iter_refs().len(), 1);
+
+    let all = Resource::Wood.iter_refs().as_slice();
+    assert!(std::ptr::eq(
+        &all[1],
+        Resource::Stone.iter_refs().next().unwrap()
+    ));
+}
+
+#[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+#[iter(start = "Down")]
+enum Compass {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[test]
+fn test_start() {
+    let mut iter = CompassIterator::new();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(
+        iter.by_ref().take(3).collect::<Vec<_>>(),
+        vec![Compass::Down, Compass::Right, Compass::Up]
+    );
+    assert_eq!(iter.remaining(), 1);
+    assert_eq!(iter.next(), Some(Compass::Left));
+    assert!(iter.next().is_none());
+
+    assert_eq!(
+        Compass::Left.iter().collect::<Vec<_>>(),
+        vec![Compass::Left, Compass::Down, Compass::Right]
+    );
+}
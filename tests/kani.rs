@@ -0,0 +1,27 @@
+// Kani sets `cfg(kani)`, which is unknown to plain cargo builds.
+#![allow(unexpected_cfgs)]
+
+use rotate_enum::{RotateEnum, ShiftEnum};
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(kani)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(kani)]
+enum Speed {
+    Slow,
+    Fast,
+}
+
+/// The harnesses are only compiled by `cargo kani`, so a normal build just keeps the enum intact.
+#[test]
+fn test_kani() {
+    assert_eq!(Direction::Right.next(), Direction::Up);
+    assert_eq!(Speed::Slow.next(), Some(Speed::Fast));
+}
@@ -0,0 +1,36 @@
+use rotate_enum::{enumeration, EnumTools};
+use std::convert::{TryFrom, TryInto};
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate)]
+enum Direction {
+    #[key('U')]
+    Up,
+    #[key('L')]
+    Left,
+    #[key('D')]
+    Down,
+    #[key('R')]
+    Right,
+}
+
+#[enumeration(count)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Mode {
+    #[key('i')]
+    Insert,
+    #[key('v')]
+    Visual,
+}
+
+#[test]
+fn test_key() {
+    assert_eq!(Direction::Up.to_char(), 'U');
+    assert_eq!(Direction::Right.next().to_char(), 'U');
+    assert_eq!(Direction::try_from('L'), Ok(Direction::Left));
+    assert_eq!(Direction::try_from('l'), Err('l'));
+
+    let mode: Result<Mode, _> = 'v'.try_into();
+    assert_eq!(mode, Ok(Mode::Visual));
+    assert_eq!(Mode::Insert.to_char(), 'i');
+}
@@ -0,0 +1,60 @@
+use rotate_enum::EnumTools;
+
+#[derive(EnumTools, Debug)]
+#[enum_tools(rotate, iter, names)]
+#[kind(ShapeKind)]
+#[rotate(self_test)]
+enum Shape {
+    Circle {
+        radius: f64,
+    },
+    #[name(renamed_from = "Rect")]
+    Rectangle(f64, f64),
+    Point,
+}
+
+impl Shape {
+    fn area(&self) -> f64 {
+        match self {
+            Shape::Circle { radius } => std::f64::consts::PI * radius * radius,
+            Shape::Rectangle(width, height) => width * height,
+            Shape::Point => 0.0,
+        }
+    }
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(shift)]
+#[kind(ModeKind)]
+enum Mode {
+    #[rotate(ordinal = 1)]
+    Dark,
+    #[rotate(ordinal = 0)]
+    Light,
+}
+
+#[test]
+fn test_kind() {
+    let shapes = [
+        Shape::Circle { radius: 1.0 },
+        Shape::Rectangle(2.0, 3.0),
+        Shape::Point,
+    ];
+    let kinds = shapes.iter().map(Shape::kind).collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [ShapeKind::Circle, ShapeKind::Rectangle, ShapeKind::Point]
+    );
+    assert_eq!(shapes[1].area(), 6.0);
+    assert_eq!(shapes[2].kind().next(), ShapeKind::Circle);
+    assert_eq!(ShapeKind::Circle.iter().collect::<Vec<_>>(), kinds);
+    assert_eq!("Rect".parse(), Ok(ShapeKind::Rectangle));
+}
+
+#[test]
+fn test_kind_of_fieldless() {
+    assert_eq!(Mode::Light.index(), 0);
+    assert_eq!(Mode::Light.next(), Some(Mode::Dark));
+    assert_eq!(Mode::Dark.kind(), ModeKind::Dark);
+    assert_eq!(ModeKind::Light.next(), Some(ModeKind::Dark));
+}
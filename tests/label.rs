@@ -0,0 +1,30 @@
+use rotate_enum::EnumTools;
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate, names)]
+enum Action {
+    #[label = "Move up"]
+    MoveUp,
+    #[label = "Move down"]
+    MoveDown,
+    r#Wait,
+}
+
+#[rotate_enum::enumeration(count)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Tool {
+    #[label = "Pen (P)"]
+    Pen,
+    #[label = ""]
+    Hidden,
+}
+
+#[test]
+fn test_label() {
+    assert_eq!(Action::MoveUp.label(), "Move up");
+    assert_eq!(Action::MoveDown.label(), "Move down");
+    assert_eq!(Action::MoveDown.name(), "MoveDown");
+    assert_eq!(Action::Wait.label(), "Wait");
+    assert_eq!(Tool::Pen.label(), "Pen (P)");
+    assert_eq!(Tool::Hidden.label(), "");
+}
@@ -0,0 +1,77 @@
+use rotate_enum::{for_each_variant, EnumTools, IterEnum, RotateEnum, ShiftEnum};
+
+macro_rules! rotating {
+    ($name:ident { $($variant:ident),* }) => {
+        #[derive(RotateEnum, IterEnum, PartialEq, Clone, Copy, Debug)]
+        #[rotate(table, default, self_test)]
+        pub enum $name {
+            $($variant,)*
+        }
+    };
+}
+
+macro_rules! fixed {
+    () => {
+        #[derive(ShiftEnum, IterEnum, PartialEq, Clone, Copy, Debug)]
+        #[rotate(hidden_module, self_test)]
+        enum Speed {
+            Slow,
+            Fast,
+        }
+
+        fn sum_speeds() -> usize {
+            let mut sum = 0;
+            for_each_variant!(Speed, |speed| sum += speed.index() + 1);
+            sum
+        }
+    };
+}
+
+macro_rules! valued {
+    ($name:ident { $($variant:ident = $value:expr),* }) => {
+        #[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+        #[enum_tools(names)]
+        enum $name {
+            $(#[value($value)] $variant,)*
+        }
+    };
+}
+
+rotating!(Direction {
+    Up,
+    Left,
+    Down,
+    Right
+});
+fixed!();
+
+const DOUBLE: f64 = 2.0;
+
+valued!(Scale { Half = 0.5, Double = DOUBLE });
+
+#[test]
+fn test_macro_rules() {
+    assert_eq!(Direction::default().prev(), Direction::Right);
+    assert_eq!(Direction::Left.iter().count(), 3);
+    assert_eq!(DirectionTable::new([1, 2, 3, 4])[Direction::Down], 3);
+
+    let speeds: SpeedIterator = Speed::Slow.iter();
+    assert_eq!(speeds.len(), 2);
+    assert_eq!(Speed::Slow.next(), Some(Speed::Fast));
+    assert_eq!(sum_speeds(), 3);
+
+    let mut directions = vec![];
+    for_each_variant!(Direction, |direction| directions.push(direction));
+    assert_eq!(directions.len(), 4);
+}
+
+#[test]
+fn test_macro_rules_value() {
+    assert_eq!(Scale::Double.value(), 2.0);
+    assert_eq!(Scale::from_value(2.0), Some(Scale::Double));
+    assert_eq!(
+        Scale::from_value(0.5).map(|scale| scale.name()),
+        Some("Half")
+    );
+    assert_eq!(Scale::from_value(1.0), None);
+}
@@ -0,0 +1,53 @@
+use rotate_enum::{EnumTools, RotateEnum};
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(maps_to = "wire::Direction")]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+mod wire {
+    use rotate_enum::EnumTools;
+
+    #[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+    #[enum_tools(count)]
+    pub enum Direction {
+        North,
+        West,
+        South,
+        East,
+    }
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(count)]
+#[rotate(maps_to = "Direction", maps_to = "wire::Direction")]
+enum Key {
+    W,
+    A,
+    S,
+    D,
+}
+
+#[test]
+fn test_maps_to() {
+    assert_eq!(wire::Direction::from(Direction::Up), wire::Direction::North);
+    assert_eq!(
+        wire::Direction::from(Direction::Right),
+        wire::Direction::East
+    );
+    assert_eq!(Direction::from(wire::Direction::South), Direction::Down);
+    let direction: Direction = wire::Direction::West.into();
+    assert_eq!(direction.next(), Direction::Down);
+}
+
+#[test]
+fn test_maps_to_several() {
+    assert_eq!(Direction::from(Key::A), Direction::Left);
+    assert_eq!(Key::from(Direction::Down), Key::S);
+    assert_eq!(wire::Direction::from(Key::D), wire::Direction::East);
+    assert_eq!(Key::from(wire::Direction::North), Key::W);
+}
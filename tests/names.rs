@@ -0,0 +1,152 @@
+use rotate_enum::EnumTools;
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(names)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(names)]
+enum Empty {}
+
+#[test]
+fn test_from_str() {
+    for &name in &Direction::VARIANT_NAMES {
+        assert_eq!(name.parse::<Direction>().map(|v| v.name()), Ok(name));
+    }
+    assert_eq!("Right".parse(), Ok(Direction::Right));
+    let error: DirectionParseError = "right".parse::<Direction>().unwrap_err();
+    assert_eq!((error.input(), error.suggestion), ("right", Some("Right")));
+    let error = "".parse::<Direction>().unwrap_err();
+    assert_eq!((error.input(), error.suggestion), ("", None));
+    let error: EmptyParseError = "Up".parse::<Empty>().unwrap_err();
+    assert_eq!((error.input(), error.suggestion), ("Up", None));
+}
+
+#[test]
+fn test_parse_error() {
+    let message = |s: &str| s.parse::<Direction>().unwrap_err().to_string();
+    assert_eq!(
+        message("leftt"),
+        "unknown variant \"leftt\" of Direction, did you mean \"Left\"?"
+    );
+    assert_eq!(
+        message("Sideways"),
+        "unknown variant \"Sideways\" of Direction"
+    );
+    // Long input is cut at a character boundary within the first 32 bytes.
+    assert_eq!(
+        message(&"ö".repeat(20)),
+        format!("unknown variant \"{}...\" of Direction", "ö".repeat(16))
+    );
+    assert_eq!(
+        format!("{:?}", "Uo".parse::<Direction>().unwrap_err()),
+        "DirectionParseError { input: \"Uo\", suggestion: Some(\"Up\") }"
+    );
+}
+
+#[test]
+fn test_suggestion() {
+    let suggestion = |s: &str| s.parse::<Direction>().unwrap_err().suggestion;
+    assert_eq!(suggestion("DOWN"), Some("Down"));
+    assert_eq!(suggestion("Dwn"), Some("Down"));
+    assert_eq!(suggestion("Rihgt"), Some("Right"));
+    assert_eq!(suggestion("Uo"), Some("Up"));
+    assert_eq!(suggestion("Upward"), None);
+    assert_eq!(suggestion("Sideways"), None);
+    assert_eq!(suggestion(""), None);
+    assert_eq!(
+        "Nigth".parse::<Theme>().unwrap_err().suggestion,
+        None,
+        "former names are not suggested"
+    );
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(names)]
+enum Command {
+    Up,
+    UpLeft,
+    Undo,
+    Quit,
+}
+
+#[test]
+fn test_from_prefix() {
+    assert_eq!(Direction::from_prefix("r"), Some(Direction::Right));
+    assert_eq!(Direction::from_prefix("DO"), Some(Direction::Down));
+    assert_eq!(Direction::from_prefix("left"), Some(Direction::Left));
+    assert_eq!(Direction::from_prefix("Lefty"), None);
+    assert_eq!(Direction::from_prefix("x"), None);
+    assert_eq!(Direction::from_prefix(""), None);
+
+    assert_eq!(Command::from_prefix("q"), Some(Command::Quit));
+    assert_eq!(Command::from_prefix("u"), None);
+    assert_eq!(Command::from_prefix("up"), Some(Command::Up));
+    assert_eq!(Command::from_prefix("upl"), Some(Command::UpLeft));
+    assert_eq!(Command::from_prefix("un"), Some(Command::Undo));
+    assert_eq!(Empty::from_prefix("u"), None);
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(iter, names)]
+enum Fruit {
+    Apple,
+    Banana,
+}
+
+#[test]
+fn test_names() {
+    assert_eq!(
+        Direction::names().collect::<Vec<_>>(),
+        Direction::VARIANT_NAMES
+    );
+    assert_eq!(Direction::names().len(), 4);
+    assert_eq!(Empty::names().next(), None);
+    assert_eq!(
+        Fruit::names().zip(Fruit::Apple.iter()).collect::<Vec<_>>(),
+        [("Apple", Fruit::Apple), ("Banana", Fruit::Banana)]
+    );
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(names)]
+enum Theme {
+    #[name(renamed_from = "Bright")]
+    Light,
+    #[name(renamed_from = "Night", renamed_from = "Black")]
+    #[name(renamed_from = "Dim")]
+    Dark,
+}
+
+#[rotate_enum::enumeration(names)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Level {
+    #[name(renamed_from = "Easy")]
+    Low,
+    High,
+}
+
+#[test]
+fn test_renamed_from() {
+    assert_eq!("Light".parse(), Ok(Theme::Light));
+    assert_eq!("Bright".parse(), Ok(Theme::Light));
+    assert_eq!("Dark".parse(), Ok(Theme::Dark));
+    assert_eq!("Night".parse(), Ok(Theme::Dark));
+    assert_eq!("Black".parse(), Ok(Theme::Dark));
+    assert_eq!("Dim".parse(), Ok(Theme::Dark));
+    assert_eq!(
+        "Gray".parse::<Theme>().map_err(|error| error.suggestion),
+        Err(None)
+    );
+    assert_eq!(Theme::Dark.name(), "Dark");
+    assert_eq!(Theme::VARIANT_NAMES, ["Light", "Dark"]);
+    assert_eq!(Theme::from_prefix("Bri"), None);
+
+    assert_eq!("Easy".parse(), Ok(Level::Low));
+    assert_eq!("High".parse(), Ok(Level::High));
+}
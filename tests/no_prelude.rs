@@ -0,0 +1,68 @@
+//! The generated code must not rely on the prelude, since enums may be generated by
+//! other macros into modules where it is shadowed or missing.
+
+#[no_implicit_prelude]
+mod generated {
+    #[derive(
+        ::rotate_enum::RotateEnum,
+        ::rotate_enum::IterEnum,
+        ::core::clone::Clone,
+        ::core::marker::Copy,
+    )]
+    #[rotate(table, default, self_test)]
+    pub enum Direction {
+        Up,
+        Left,
+        Down,
+        Right,
+    }
+
+    #[derive(::rotate_enum::ShiftEnum, ::core::clone::Clone, ::core::marker::Copy)]
+    #[rotate(hidden_module, self_test)]
+    pub enum Speed {
+        Slow,
+        Fast,
+    }
+
+    #[derive(::rotate_enum::EnumTools, ::core::clone::Clone, ::core::marker::Copy)]
+    #[enum_tools(iter)]
+    #[iter(flatten)]
+    pub enum Menu {
+        About,
+        Speed(super::Speed2),
+    }
+
+    #[derive(::rotate_enum::EnumTools, ::core::clone::Clone, ::core::marker::Copy)]
+    #[enum_tools(names)]
+    pub enum Unit {
+        #[value(1.0)]
+        #[key('m')]
+        Meter,
+        #[value(1000.0)]
+        #[key('k')]
+        Kilometer,
+    }
+}
+
+#[derive(rotate_enum::IterEnum, Clone, Copy, PartialEq, Debug)]
+pub enum Speed2 {
+    Slow,
+    Fast,
+}
+
+use generated::*;
+use std::convert::TryFrom;
+
+#[test]
+fn test_no_prelude() {
+    assert!(matches!(Direction::default().prev(), Direction::Right));
+    assert_eq!(Direction::Up.iter_indexed().count(), 4);
+    assert_eq!(DirectionTable::new([1, 2, 3, 4])[Direction::Left], 2);
+    assert_eq!(Speed::Slow.saturating_add(5).index(), 1);
+    assert_eq!(MenuIterator::new().len(), 3);
+    let last = MenuIterator::new().last();
+    assert!(matches!(last, Some(Menu::Speed(speed)) if speed == Speed2::Fast));
+    assert!(matches!("Meter".parse(), Ok(Unit::Meter)));
+    assert!(matches!(Unit::from_value(1000.0), Some(Unit::Kilometer)));
+    assert!(matches!(Unit::try_from('m'), Ok(Unit::Meter)));
+}
@@ -0,0 +1,53 @@
+use rotate_enum::{enumeration, IterEnum, RotateEnum};
+
+#[derive(RotateEnum, IterEnum, PartialEq, Clone, Copy, Debug)]
+enum Direction {
+    #[rotate(ordinal = 2)]
+    Down,
+    #[rotate(ordinal = 0)]
+    Up,
+    #[rotate(ordinal = 3)]
+    Right,
+    #[rotate(ordinal = 1)]
+    Left,
+}
+
+#[enumeration(rotate, names)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Season {
+    #[rotate(ordinal = 1)]
+    Summer,
+    #[rotate(ordinal = 0)]
+    Spring,
+    #[rotate(ordinal = 2)]
+    Autumn,
+}
+
+#[test]
+fn test_ordinal() {
+    assert_eq!(Direction::Up.index(), 0);
+    assert_eq!(Direction::Down.index(), 2);
+    assert_eq!(Direction::from_index(3), Some(Direction::Right));
+    assert_eq!(Direction::Up.next(), Direction::Left);
+    assert_eq!(Direction::Up.prev(), Direction::Right);
+}
+
+#[test]
+fn test_ordinal_iter() {
+    let all = Direction::Up.iter().collect::<Vec<_>>();
+    assert_eq!(
+        all,
+        [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right
+        ]
+    );
+}
+
+#[test]
+fn test_ordinal_names() {
+    assert_eq!(Season::VARIANT_NAMES, ["Spring", "Summer", "Autumn"]);
+    assert_eq!(Season::Autumn.next().name(), "Spring");
+}
@@ -0,0 +1,40 @@
+use rotate_enum::{enumeration, EnumTools};
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate)]
+enum Direction {
+    #[pair("Down")]
+    Up,
+    #[pair("Right")]
+    Left,
+    #[pair("Up")]
+    Down,
+    #[pair("Left")]
+    Right,
+}
+
+#[enumeration(count)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Hand {
+    #[pair("Right")]
+    Left,
+    #[pair("Both")]
+    Both,
+    #[pair("Left")]
+    Right,
+}
+
+#[test]
+fn test_pair() {
+    assert_eq!(Direction::Up.flipped(), Direction::Down);
+    assert_eq!(Direction::Left.flipped(), Direction::Right);
+    assert_eq!(Direction::Right.next().flipped(), Direction::Down);
+    for i in 0..Direction::COUNT {
+        let direction = Direction::from_index(i).unwrap();
+        assert_eq!(direction.flipped().flipped(), direction);
+    }
+
+    assert_eq!(Hand::Left.flipped(), Hand::Right);
+    assert_eq!(Hand::Both.flipped(), Hand::Both);
+    assert_eq!(Hand::from_index(2).map(Hand::flipped), Some(Hand::Left));
+}
@@ -0,0 +1,31 @@
+#![allow(non_camel_case_types)]
+
+use rotate_enum::{EnumTools, IterEnum};
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate, iter, names)]
+enum Keyword {
+    r#type,
+    r#match,
+    Plain,
+}
+
+#[derive(IterEnum, PartialEq, Clone, Copy, Debug)]
+enum r#loop {
+    r#while,
+    r#for,
+}
+
+#[test]
+fn test_raw() {
+    assert_eq!(Keyword::r#type.next(), Keyword::r#match);
+    assert_eq!(Keyword::r#type.prev(), Keyword::Plain);
+    assert_eq!(Keyword::r#match.name(), "match");
+    assert_eq!(Keyword::VARIANT_NAMES, ["type", "match", "Plain"]);
+    assert_eq!(Keyword::r#match.iter().count(), 2);
+
+    assert_eq!(
+        loopIterator::new().collect::<Vec<_>>(),
+        vec![r#loop::r#while, r#loop::r#for]
+    );
+}
@@ -0,0 +1,66 @@
+use rotate_enum::{EnumTools, RotateEnum};
+use std::convert::TryFrom;
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(repr_conversions)]
+#[repr(i8)]
+enum Status {
+    Failed = -2,
+    Busy = -1,
+    Ok = 0,
+    Done = 3,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(count)]
+#[rotate(repr_conversions)]
+#[repr(u16)]
+enum Port {
+    Http = 80,
+    Https = 443,
+}
+
+// Without `#[rotate(repr_conversions)]`, the enum can convert from its repr in its own way.
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[repr(u8)]
+enum Level {
+    Low = 1,
+    High = 2,
+}
+
+impl TryFrom<u8> for Level {
+    type Error = ();
+    fn try_from(value: u8) -> Result<Self, ()> {
+        match value {
+            0..=1 => Ok(Level::Low),
+            2..=9 => Ok(Level::High),
+            _ => Err(()),
+        }
+    }
+}
+
+#[test]
+fn test_signed_repr() {
+    assert_eq!(Status::Failed.to_repr(), -2);
+    assert_eq!(Status::Busy.next().to_repr(), 0);
+    assert_eq!(Status::try_from(-1), Ok(Status::Busy));
+    assert_eq!(Status::try_from(3), Ok(Status::Done));
+    assert_eq!(Status::try_from(1), Err(1));
+    assert_eq!(Status::try_from(-128), Err(-128));
+    assert_eq!(Status::Failed.index(), 0);
+}
+
+#[test]
+fn test_unsigned_repr() {
+    assert_eq!(Port::Https.to_repr(), 443u16);
+    assert_eq!(Port::try_from(80u16), Ok(Port::Http));
+    assert_eq!(Port::try_from(8080u16), Err(8080));
+}
+
+#[test]
+fn test_own_try_from() {
+    assert_eq!(Level::try_from(0), Ok(Level::Low));
+    assert_eq!(Level::try_from(9), Ok(Level::High));
+    assert_eq!(Level::try_from(10), Err(()));
+    assert_eq!(Level::Low.next(), Level::High);
+}
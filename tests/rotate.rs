@@ -25,3 +25,170 @@ fn test_rotate() {
     assert!(down.prev() == left);
     assert!(right.prev() == down);
 }
+
+#[test]
+fn test_index() {
+    assert_eq!(Direction::COUNT, 4);
+    assert!(Direction::Up.index() == 0);
+    assert!(Direction::Right.index() == 3);
+    assert!(Direction::from_index(1) == Some(Direction::Left));
+    assert!(Direction::from_index(4).is_none());
+}
+
+#[test]
+fn test_neighbors() {
+    assert!(Direction::Up.neighbors() == (Direction::Right, Direction::Left));
+    assert!(Direction::Left.neighbors() == (Direction::Up, Direction::Down));
+    assert!(Direction::Right.neighbors() == (Direction::Down, Direction::Up));
+}
+
+#[test]
+fn test_cmp_from() {
+    use std::cmp::Ordering;
+
+    assert!(Direction::Right.cmp_from(Direction::Down, Direction::Up) == Ordering::Less);
+    assert!(Direction::Up.cmp_from(Direction::Up, Direction::Right) == Ordering::Less);
+    assert!(Direction::Left.cmp_from(Direction::Down, Direction::Left) == Ordering::Equal);
+    assert!(Direction::Down.cmp_from(Direction::Left, Direction::Up) == Ordering::Less);
+    assert!(Direction::Up.cmp_from(Direction::Left, Direction::Right) == Ordering::Greater);
+}
+
+#[test]
+fn test_wrapping() {
+    assert!(Direction::Up.wrapping_add(0) == Direction::Up);
+    assert!(Direction::Up.wrapping_add(3) == Direction::Right);
+    assert!(Direction::Down.wrapping_add(6) == Direction::Up);
+    assert!(Direction::Down.wrapping_add(usize::MAX) == Direction::Left);
+    assert!(Direction::Up.wrapping_sub(1) == Direction::Right);
+    assert!(Direction::Left.wrapping_sub(9) == Direction::Up);
+    assert!(Direction::Left.wrapping_sub(usize::MAX) == Direction::Left.wrapping_add(1));
+}
+
+#[test]
+fn test_advance_wrapping() {
+    assert!(Direction::Up.advance_wrapping(0) == (Direction::Up, 0));
+    assert!(Direction::Right.advance_wrapping(1) == (Direction::Up, 1));
+    assert!(Direction::Down.advance_wrapping(9) == (Direction::Right, 2));
+    assert!(Direction::Right.advance_wrapping(usize::MAX) == (Direction::Down, usize::MAX / 4 + 1));
+}
+
+#[test]
+fn test_path_to() {
+    let path = |from: Direction, to| from.path_to(to).collect::<Vec<_>>();
+    assert!(path(Direction::Up, Direction::Up).is_empty());
+    assert!(path(Direction::Up, Direction::Left) == [Direction::Left]);
+    assert!(
+        path(Direction::Down, Direction::Left)
+            == [Direction::Right, Direction::Up, Direction::Left]
+    );
+
+    let shortest = |from: Direction, to| from.shortest_path_to(to).collect::<Vec<_>>();
+    assert!(shortest(Direction::Up, Direction::Up).is_empty());
+    assert!(shortest(Direction::Down, Direction::Left) == [Direction::Left]);
+    assert!(shortest(Direction::Right, Direction::Left) == [Direction::Up, Direction::Left]);
+    assert!(shortest(Direction::Left, Direction::Right) == [Direction::Down, Direction::Right]);
+    assert!(shortest(Direction::Up, Direction::Right) == [Direction::Right]);
+}
+
+#[test]
+fn test_by_distance_from() {
+    let order = |anchor| Direction::by_distance_from(anchor).collect::<Vec<_>>();
+    assert!(
+        order(Direction::Up)
+            == [
+                Direction::Up,
+                Direction::Left,
+                Direction::Right,
+                Direction::Down
+            ]
+    );
+    assert!(
+        order(Direction::Right)
+            == [
+                Direction::Right,
+                Direction::Up,
+                Direction::Down,
+                Direction::Left
+            ]
+    );
+}
+
+#[test]
+fn test_all_rotations() {
+    let rotations = Direction::all_rotations()
+        .map(|rotation| rotation.collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert!(rotations.len() == 4);
+    assert!(
+        rotations[1]
+            == [
+                Direction::Left,
+                Direction::Down,
+                Direction::Right,
+                Direction::Up
+            ]
+    );
+    for (start, rotation) in rotations.iter().enumerate() {
+        for (step, direction) in rotation.iter().enumerate() {
+            assert!(direction.index() == (start + step) % 4);
+        }
+    }
+}
+
+#[test]
+fn test_for_each() {
+    let mut indices = vec![];
+    Direction::for_each(|direction| indices.push(direction.index()));
+    assert!(indices == [0, 1, 2, 3]);
+}
+
+#[test]
+fn test_map_variants() {
+    let names = Direction::map_variants(|direction| match direction {
+        Direction::Up => "up",
+        Direction::Left => "left",
+        Direction::Down => "down",
+        Direction::Right => "right",
+    });
+    assert!(names == ["up", "left", "down", "right"]);
+    assert!(names[Direction::Left.next().index()] == "down");
+}
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(self_test)]
+enum State {
+    Idle,
+    Running,
+    #[rotate(self_loop)]
+    Error,
+    Done,
+}
+
+#[test]
+fn test_self_loop() {
+    assert_eq!(State::Idle.next(), State::Running);
+    assert_eq!(State::Running.next(), State::Done);
+    assert_eq!(State::Done.next(), State::Idle);
+    assert_eq!(State::Idle.prev(), State::Done);
+    assert_eq!(State::Done.prev(), State::Running);
+    assert_eq!(State::Error.next(), State::Error);
+    assert_eq!(State::Error.prev(), State::Error);
+    assert_eq!(State::Error.neighbors(), (State::Error, State::Error));
+    assert_eq!(State::Running.wrapping_add(1), State::Done);
+    assert_eq!(State::Idle.wrapping_sub(1), State::Done);
+    assert_eq!(State::Done.advance_wrapping(2), (State::Running, 1));
+    assert_eq!(State::Error.wrapping_add(2), State::Error);
+    assert_eq!(
+        State::Idle.path_to(State::Done).collect::<Vec<_>>(),
+        [State::Running, State::Done]
+    );
+    assert_eq!(State::Idle.path_to(State::Error).count(), 0);
+    assert_eq!(
+        State::Running.cmp_from(State::Idle, State::Error),
+        std::cmp::Ordering::Less
+    );
+    assert_eq!(
+        State::by_distance_from(State::Running).collect::<Vec<_>>(),
+        [State::Running, State::Done, State::Idle, State::Error]
+    );
+}
@@ -0,0 +1,35 @@
+use rotate_enum::rotate_enum;
+
+rotate_enum! {
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    pub enum Direction {
+        Up,
+        Left,
+        Down,
+        Right,
+    }
+}
+
+rotate_enum! {
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    #[enum_tools(shift)]
+    enum Step {
+        First,
+        Second,
+    }
+}
+
+#[test]
+fn test_rotate_enum() {
+    assert_eq!(Direction::Right.next(), Direction::Up);
+    assert_eq!(Direction::Up.prev(), Direction::Right);
+    assert_eq!(
+        Direction::Down.iter().collect::<Vec<_>>(),
+        vec![Direction::Down, Direction::Right]
+    );
+    assert_eq!(Direction::COUNT, 4);
+    assert_eq!(Direction::Left.name(), "Left");
+
+    assert_eq!(Step::First.next(), Some(Step::Second));
+    assert_eq!(Step::Second.next(), None);
+}
@@ -0,0 +1,41 @@
+use rotate_enum::{EnumTools, IterEnum, RotateEnum, ShiftEnum};
+
+#[derive(RotateEnum, IterEnum)]
+#[rotate(self_test)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(ShiftEnum)]
+#[rotate(self_test)]
+enum Step {
+    First,
+    Second,
+    Third,
+}
+
+#[derive(EnumTools)]
+#[enum_tools(rotate, iter, names)]
+#[rotate(self_test)]
+#[iter(start = "Horizontal")]
+enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+#[derive(RotateEnum, IterEnum)]
+#[rotate(self_test)]
+enum Single {
+    Only,
+}
+
+#[test]
+fn test_self_test() {
+    assert_eq!(Direction::Up.next().index(), 1);
+    assert_eq!(Step::Third.next().map(|s| s.index()), None);
+    assert_eq!(Axis::Vertical.name(), "Vertical");
+    assert_eq!(Single::Only.next().index(), 0);
+}
@@ -0,0 +1,88 @@
+use rotate_enum::{rotate_sequence, EnumTools, RotateEnum};
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate)]
+enum Phase {
+    #[rotate(ordinal = 1)]
+    Waxing,
+    #[rotate(ordinal = 0)]
+    New,
+    #[rotate(ordinal = 2)]
+    Full,
+}
+
+const TURNS: [Direction; 3] = rotate_sequence!(Direction::Left, 3);
+static TWICE: [Direction; 6] = rotate_sequence!(Direction::Down, 6);
+
+#[test]
+fn test_rotate_sequence() {
+    assert_eq!(TURNS, [Direction::Left, Direction::Down, Direction::Right]);
+    assert_eq!(
+        TWICE,
+        [
+            Direction::Down,
+            Direction::Right,
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right
+        ]
+    );
+    let empty: [Direction; 0] = rotate_sequence!(Direction::Up, 0);
+    assert_eq!(empty, []);
+}
+
+#[test]
+fn test_rotate_sequence_ordinal() {
+    assert_eq!(
+        rotate_sequence!(Phase::Full, 3),
+        [Phase::Full, Phase::New, Phase::Waxing]
+    );
+}
+
+mod child {
+    use super::Direction;
+    use rotate_enum::rotate_sequence;
+
+    #[test]
+    fn test_rotate_sequence_in_child() {
+        assert_eq!(
+            rotate_sequence!(Direction::Right, 2),
+            [Direction::Right, Direction::Up]
+        );
+    }
+}
+
+mod compass {
+    use rotate_enum::RotateEnum;
+
+    #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+    pub enum Direction {
+        North,
+        East,
+        South,
+        West,
+    }
+}
+
+mod sibling {
+    use rotate_enum::rotate_sequence;
+
+    pub const TURNS: [crate::compass::Direction; 2] =
+        rotate_sequence!(crate::compass::Direction::West, 2);
+}
+
+#[test]
+fn test_rotate_sequence_path() {
+    use compass::Direction::*;
+    assert_eq!(sibling::TURNS, [West, North]);
+    assert_eq!(rotate_sequence!(compass::Direction::East, 2), [East, South]);
+}
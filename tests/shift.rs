@@ -9,6 +9,7 @@ enum Direction {
 }
 
 #[test]
+#[allow(clippy::partialeq_to_none)]
 fn test_shift() {
     let up = Direction::Up;
     let left = Direction::Left;
@@ -25,3 +26,140 @@ fn test_shift() {
     assert!(down.prev() == Some(left));
     assert!(right.prev() == Some(down));
 }
+
+#[test]
+fn test_index() {
+    assert_eq!(Direction::COUNT, 4);
+    assert!(Direction::Down.index() == 2);
+    assert!(Direction::from_index(3) == Some(Direction::Right));
+    assert!(Direction::from_index(4).is_none());
+}
+
+#[test]
+fn test_from_index_wrapping() {
+    assert!(Direction::from_index_wrapping(1) == Direction::Left);
+    assert!(Direction::from_index_wrapping(4) == Direction::Up);
+    assert!(Direction::from_index_wrapping(11) == Direction::Right);
+    assert!(Direction::from_index_wrapping(usize::MAX) == Direction::Right);
+}
+
+#[test]
+fn test_from_index_clamped() {
+    assert!(Direction::from_index_clamped(0) == Direction::Up);
+    assert!(Direction::from_index_clamped(2) == Direction::Down);
+    assert!(Direction::from_index_clamped(4) == Direction::Right);
+    assert!(Direction::from_index_clamped(usize::MAX) == Direction::Right);
+}
+
+#[test]
+fn test_count_occurrences() {
+    let events = vec![Direction::Left, Direction::Down, Direction::Left];
+    assert_eq!(Direction::count_occurrences(events), [0, 2, 1, 0]);
+    assert_eq!(Direction::count_occurrences(None), [0; 4]);
+}
+
+#[test]
+fn test_next_or() {
+    assert!(Direction::Up.next_or(Direction::Down) == Direction::Left);
+    assert!(Direction::Right.next_or(Direction::Down) == Direction::Down);
+    assert!(Direction::Left.prev_or(Direction::Down) == Direction::Up);
+    assert!(Direction::Up.prev_or(Direction::Down) == Direction::Down);
+}
+
+#[test]
+fn test_checked() {
+    assert!(Direction::Up.checked_add(0) == Some(Direction::Up));
+    assert!(Direction::Up.checked_add(3) == Some(Direction::Right));
+    assert!(Direction::Up.checked_add(4).is_none());
+    assert!(Direction::Down.checked_add(-2) == Some(Direction::Up));
+    assert!(Direction::Down.checked_add(-3).is_none());
+    assert!(Direction::Down.checked_add(isize::MAX).is_none());
+    assert!(Direction::Down.checked_add(isize::MIN).is_none());
+
+    assert!(Direction::Right.checked_sub(3) == Some(Direction::Up));
+    assert!(Direction::Right.checked_sub(-1).is_none());
+    assert!(Direction::Right.checked_sub(isize::MIN).is_none());
+}
+
+#[test]
+fn test_saturating() {
+    assert!(Direction::Up.saturating_add(2) == Direction::Down);
+    assert!(Direction::Up.saturating_add(10) == Direction::Right);
+    assert!(Direction::Up.saturating_add(isize::MAX) == Direction::Right);
+    assert!(Direction::Down.saturating_add(-10) == Direction::Up);
+    assert!(Direction::Down.saturating_sub(1) == Direction::Left);
+    assert!(Direction::Down.saturating_sub(-10) == Direction::Right);
+    assert!(Direction::Down.saturating_sub(isize::MIN) == Direction::Right);
+}
+
+#[test]
+fn test_shift_by() {
+    assert!(Direction::Up.shift_by(2) == (Direction::Down, 2));
+    assert!(Direction::Up.shift_by(10) == (Direction::Right, 3));
+    assert!(Direction::Down.shift_by(-10) == (Direction::Up, -2));
+    assert!(Direction::Down.shift_by(0) == (Direction::Down, 0));
+    assert!(Direction::Left.shift_by(isize::MIN) == (Direction::Up, -1));
+}
+
+#[test]
+fn test_remaining() {
+    assert_eq!(Direction::Up.remaining_forward(), 3);
+    assert_eq!(Direction::Up.remaining_backward(), 0);
+    assert_eq!(Direction::Right.remaining_forward(), 0);
+    assert_eq!(Direction::Right.remaining_backward(), 3);
+}
+
+#[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+#[shift(overflow_to = "Summary")]
+#[rotate(self_test)]
+enum Step {
+    Summary,
+    Name,
+    Address,
+}
+
+#[rotate_enum::enumeration(shift)]
+#[shift(overflow_to = "Idle")]
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Phase {
+    Start,
+    Idle,
+    End,
+}
+
+#[test]
+fn test_overflow_to() {
+    assert_eq!(Step::Summary.next(), Some(Step::Name));
+    assert_eq!(Step::Address.next(), Some(Step::Summary));
+    assert_eq!(Step::Summary.prev(), Some(Step::Summary));
+    assert_eq!(Step::Address.next_or(Step::Name), Step::Summary);
+    assert_eq!(Step::Address.checked_add(1), None);
+
+    assert_eq!(Phase::End.next(), Some(Phase::Idle));
+    assert_eq!(Phase::Start.prev(), Some(Phase::Idle));
+    assert_eq!(Phase::Idle.next(), Some(Phase::End));
+}
+
+#[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+#[shift(iterator)]
+#[rotate(self_test)]
+enum Stage {
+    Boot,
+    Load,
+    Run,
+}
+
+#[test]
+fn test_iterator() {
+    let mut stage = Stage::Boot;
+    let visited = stage.by_ref().collect::<Vec<_>>();
+    assert_eq!(visited, [Stage::Load, Stage::Run]);
+    assert_eq!(stage, Stage::Run);
+    assert_eq!(stage.next(), None);
+    assert_eq!(stage, Stage::Run);
+
+    assert_eq!(Stage::Load.next(), Some(Stage::Run));
+    assert_eq!(Stage::Run.prev(), Some(Stage::Load));
+    assert_eq!(Stage::Run.next_or(Stage::Boot), Stage::Boot);
+    assert_eq!(Stage::Boot.collect::<Vec<_>>(), [Stage::Load, Stage::Run]);
+}
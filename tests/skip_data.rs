@@ -0,0 +1,172 @@
+use rotate_enum::{enum_table, rotate_sequence, EnumTools, IterEnum, RotateEnum, ShiftEnum};
+
+#[derive(RotateEnum, IterEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(skip_data, table, self_test, description)]
+enum Tool {
+    /// Draws thin lines.
+    Pen,
+    /// Paints wide strokes.
+    Brush {
+        size: u8,
+    },
+    Eraser,
+    Stamp(char),
+    Fill,
+}
+
+#[derive(EnumTools, PartialEq, Debug)]
+#[enum_tools(rotate, names)]
+#[rotate(skip_data)]
+enum Light {
+    #[value(0.0)]
+    Off,
+    #[label = "Dim"]
+    Dimmed(f32),
+    #[label = "Full"]
+    #[value(1.0)]
+    On,
+}
+
+#[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(skip_data, self_test)]
+enum Gear {
+    Manual(u8),
+    Low,
+    High,
+    Custom { ratio: u8 },
+}
+
+#[test]
+fn test_skip_data() {
+    assert_eq!(Tool::COUNT, 3);
+    assert_eq!(Tool::Pen.next(), Tool::Eraser);
+    assert_eq!(Tool::Eraser.next(), Tool::Fill);
+    assert_eq!(Tool::Fill.next(), Tool::Pen);
+    assert_eq!(Tool::Pen.prev(), Tool::Fill);
+    assert_eq!(Tool::Fill.index(), 2);
+    assert_eq!(
+        Tool::Pen.iter().collect::<Vec<_>>(),
+        [Tool::Pen, Tool::Eraser, Tool::Fill]
+    );
+    assert_eq!(rotate_sequence!(Tool::Fill, 2), [Tool::Fill, Tool::Pen]);
+    let cursors = enum_table!(Tool => &str { Pen: "pen", Eraser: "eraser", Fill: "bucket" });
+    assert_eq!(cursors[Tool::Fill], "bucket");
+
+    assert_eq!(Light::Off.next(), Light::On);
+    assert_eq!(Light::On.name(), "On");
+    assert_eq!(Light::On.label(), "Full");
+    assert_eq!(!Light::On, Light::Off);
+}
+
+#[test]
+fn test_skip_data_bridge() {
+    // Brush and Stamp share the indices of Eraser and Fill, the unit variants declared after them.
+    let brush = Tool::Brush { size: 3 };
+    let stamp = Tool::Stamp('x');
+    assert_eq!(brush.index(), 1);
+    assert_eq!(stamp.index(), 2);
+    assert_eq!(brush.next(), Tool::Eraser);
+    assert_eq!(brush.prev(), Tool::Pen);
+    assert_eq!(stamp.next(), Tool::Fill);
+    assert_eq!(stamp.prev(), Tool::Eraser);
+    assert_eq!(brush.neighbors(), (Tool::Pen, Tool::Eraser));
+    assert_eq!(stamp.wrapping_add(1), Tool::Pen);
+    assert_eq!(stamp.wrapping_sub(1), Tool::Eraser);
+    assert_eq!(stamp.advance_wrapping(2), (Tool::Eraser, 1));
+    assert_eq!(brush.path_to(Tool::Fill).collect::<Vec<_>>(), [Tool::Fill]);
+    assert_eq!(
+        brush.shortest_path_to(Tool::Pen).collect::<Vec<_>>(),
+        [Tool::Pen]
+    );
+    assert_eq!(
+        Tool::Pen.cmp_from(brush, Tool::Eraser),
+        std::cmp::Ordering::Greater
+    );
+    assert_eq!(
+        Tool::by_distance_from(stamp).collect::<Vec<_>>(),
+        [Tool::Fill, Tool::Pen, Tool::Eraser]
+    );
+    assert_eq!(stamp.iter().collect::<Vec<_>>(), [Tool::Fill]);
+    assert_eq!(stamp.iter_refs().collect::<Vec<_>>(), [&Tool::Fill]);
+    assert_eq!(
+        brush.iter_indexed().collect::<Vec<_>>(),
+        [(1, Tool::Eraser), (2, Tool::Fill)]
+    );
+    assert_eq!(
+        Tool::count_occurrences([brush, stamp, Tool::Fill]),
+        [0, 1, 2]
+    );
+    assert_eq!(brush.description(), "Paints wide strokes.");
+    assert_eq!(stamp.description(), "");
+    let cursors = enum_table!(Tool => &str { Pen: "pen", Eraser: "eraser", Fill: "bucket" });
+    assert_eq!(cursors[stamp], "bucket");
+
+    // Light is named and labeled by the data variant itself, and shares the rest with On.
+    let dimmed = Light::Dimmed(0.5);
+    assert_eq!(dimmed.index(), 1);
+    assert_eq!(Light::Dimmed(0.5).next(), Light::On);
+    assert_eq!(Light::Dimmed(0.5).prev(), Light::Off);
+    assert_eq!(dimmed.name(), "Dimmed");
+    assert_eq!(dimmed.label(), "Dim");
+    assert_eq!(dimmed.value(), 1.0);
+    assert_eq!(!Light::Dimmed(0.5), Light::Off);
+    assert!(bool::from(Light::Dimmed(0.5)));
+
+    // A ShiftEnum doesn't wrap around, so the data variants at either end have no neighbor there.
+    assert_eq!(Gear::Manual(1).next(), Some(Gear::Low));
+    assert_eq!(Gear::Manual(1).prev(), None);
+    assert_eq!(Gear::Custom { ratio: 3 }.next(), None);
+    assert_eq!(Gear::Custom { ratio: 3 }.prev(), Some(Gear::High));
+    assert_eq!(Gear::Custom { ratio: 3 }.next_or(Gear::Low), Gear::Low);
+    assert_eq!(Gear::Custom { ratio: 3 }.index(), 0);
+}
+
+#[test]
+fn test_skip_data_shift_steps() {
+    // The data variants count their steps like next() and prev() bridge over them.
+    let (manual, custom) = (Gear::Manual(1), Gear::Custom { ratio: 3 });
+    assert_eq!(manual.remaining_forward(), 2);
+    assert_eq!(manual.remaining_backward(), 0);
+    assert_eq!(custom.remaining_forward(), 0);
+    assert_eq!(custom.remaining_backward(), 2);
+    assert_eq!(manual.checked_add(0), Some(manual));
+    assert_eq!(manual.checked_add(1), Some(Gear::Low));
+    assert_eq!(manual.checked_add(2), Some(Gear::High));
+    assert_eq!(manual.checked_add(3), None);
+    assert_eq!(manual.checked_sub(1), None);
+    assert_eq!(custom.checked_sub(1), Some(Gear::High));
+    assert_eq!(custom.checked_sub(2), Some(Gear::Low));
+    assert_eq!(custom.checked_add(1), None);
+    assert_eq!(manual.saturating_add(5), Gear::High);
+    assert_eq!(custom.saturating_add(5), custom);
+    assert_eq!(custom.shift_by(-5), (Gear::Low, -2));
+    assert_eq!(Gear::Low.remaining_forward(), 1);
+}
+
+impl Tool {
+    fn size(&self) -> u8 {
+        match *self {
+            Tool::Brush { size } => size,
+            Tool::Stamp(_) => 2,
+            _ => 1,
+        }
+    }
+}
+
+impl Light {
+    fn level(&self) -> f32 {
+        match *self {
+            Light::Off => 0.0,
+            Light::Dimmed(level) => level,
+            Light::On => 1.0,
+        }
+    }
+}
+
+#[test]
+fn test_skip_data_fields() {
+    assert_eq!(Tool::Brush { size: 3 }.size(), 3);
+    assert_eq!(Tool::Stamp('x').size(), 2);
+    assert_eq!(Light::Dimmed(0.5).level(), 0.5);
+    assert_eq!(Light::Off.next().level(), 1.0);
+}
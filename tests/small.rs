@@ -0,0 +1,38 @@
+use rotate_enum::{IterEnum, RotateEnum, ShiftEnum};
+
+#[derive(RotateEnum, IterEnum, PartialEq, Clone, Copy, Debug)]
+enum Empty {}
+
+#[derive(RotateEnum, IterEnum, PartialEq, Clone, Copy, Debug)]
+enum Single {
+    Only,
+}
+
+#[derive(ShiftEnum, PartialEq, Clone, Copy, Debug)]
+enum SingleStep {
+    Only,
+}
+
+#[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+enum Switch {
+    On,
+    Off,
+}
+
+#[test]
+fn test_small() {
+    assert_eq!(Empty::COUNT, 0);
+    assert_eq!(Empty::from_index(0), None);
+    assert_eq!(EmptyIterator::new().count(), 0);
+
+    assert_eq!(Single::Only.next(), Single::Only);
+    assert_eq!(Single::Only.prev(), Single::Only);
+    assert_eq!(Single::Only.iter().collect::<Vec<_>>(), vec![Single::Only]);
+
+    assert_eq!(SingleStep::Only.next(), None);
+    assert_eq!(SingleStep::Only.prev(), None);
+
+    assert_eq!(Switch::On.toggle(), Switch::Off);
+    assert_eq!(Switch::Off.toggle(), Switch::On);
+    assert_eq!(Switch::On.toggle().toggle(), Switch::On);
+}
@@ -0,0 +1,60 @@
+use rotate_enum::{enumeration, EnumTools};
+use std::convert::TryFrom;
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate, iter, names)]
+#[subset(Cardinal: Up, Left, Down, Right)]
+#[subset(Vertical: Down, Up)]
+pub enum Direction {
+    Up,
+    UpLeft,
+    Left,
+    DownLeft,
+    Down,
+    DownRight,
+    Right,
+    UpRight,
+}
+
+#[enumeration(shift)]
+#[subset(Small: One, Two)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Number {
+    One,
+    Two,
+    Three,
+}
+
+#[test]
+fn test_subset() {
+    assert_eq!(Cardinal::COUNT, 4);
+    assert_eq!(Cardinal::Up.next(), Cardinal::Left);
+    assert_eq!(Cardinal::Right.next(), Cardinal::Up);
+    assert_eq!(Cardinal::Up.iter().count(), 4);
+    assert_eq!(Cardinal::Down.name(), "Down");
+    assert_eq!(Vertical::VARIANT_NAMES, ["Down", "Up"]);
+    assert_eq!(Vertical::Up.next(), Vertical::Down);
+}
+
+#[test]
+fn test_subset_conversions() {
+    for cardinal in Cardinal::Up.iter() {
+        let direction = Direction::from(cardinal);
+        assert_eq!(direction.name(), cardinal.name());
+        assert_eq!(Cardinal::try_from(direction), Ok(cardinal));
+    }
+    assert_eq!(
+        Cardinal::try_from(Direction::UpLeft),
+        Err(Direction::UpLeft)
+    );
+    assert_eq!(Vertical::try_from(Direction::Left), Err(Direction::Left));
+}
+
+#[test]
+fn test_subset_shift() {
+    assert_eq!(Small::One.next(), Some(Small::Two));
+    assert_eq!(Small::Two.next(), None);
+    assert_eq!(Number::from(Small::Two).next(), Some(Number::Three));
+    assert_eq!(Small::try_from(Number::Three), Err(Number::Three));
+    assert_eq!(Number::One.prev(), None);
+}
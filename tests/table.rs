@@ -0,0 +1,102 @@
+use rotate_enum::{enum_table, IterEnum, RotateEnum};
+
+#[derive(RotateEnum, IterEnum, PartialEq, Clone, Copy, Debug)]
+#[rotate(table)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+const ANGLES: DirectionTable<u16> = DirectionTable::new([90, 180, 270, 0]);
+static NAMES: DirectionTable<&str> = DirectionTable::new(["up", "left", "down", "right"]);
+
+#[test]
+fn test_table() {
+    assert_eq!(ANGLES[Direction::Left], 180);
+    assert_eq!(NAMES.get(Direction::Right), &"right");
+    assert_eq!(ANGLES.as_array(), &[90, 180, 270, 0]);
+
+    let mut visits = DirectionTable::new([0; 4]);
+    for direction in Direction::Left.iter() {
+        visits[direction] += 1;
+    }
+    *visits.get_mut(Direction::Up) += 10;
+    assert_eq!(visits.into_array(), [10, 1, 1, 1]);
+}
+
+const SPEEDS: DirectionTable<f32> = enum_table!(Direction => f32 {
+    Right: 4.0,
+    Up: 1.0,
+    Down: 3.0,
+    Left: 2.0,
+});
+
+#[test]
+fn test_enum_table() {
+    assert_eq!(SPEEDS.as_array(), &[1.0, 2.0, 3.0, 4.0]);
+    let names = enum_table!(Direction => String {
+        Up: "up".to_string(),
+        Left: "left".to_string(),
+        Down: "down".to_string(),
+        Right: "right".to_string()
+    });
+    assert_eq!(names[Direction::Down], "down");
+}
+
+#[test]
+fn test_enum_table_evaluates_once() {
+    let mut evaluated = vec![];
+    let mut value = |direction: Direction| {
+        evaluated.push(direction);
+        direction.index()
+    };
+    let indices = enum_table!(Direction => usize {
+        Down: value(Direction::Down),
+        Up: value(Direction::Up),
+        Right: value(Direction::Right),
+        Left: value(Direction::Left),
+    });
+    assert_eq!(indices.into_array(), [0, 1, 2, 3]);
+    assert_eq!(
+        evaluated,
+        [
+            Direction::Down,
+            Direction::Up,
+            Direction::Right,
+            Direction::Left
+        ]
+    );
+}
+
+mod compass {
+    use rotate_enum::RotateEnum;
+
+    #[derive(RotateEnum, PartialEq, Clone, Copy, Debug)]
+    #[rotate(table, hidden_module)]
+    pub enum Direction {
+        North,
+        East,
+        South,
+        West,
+    }
+}
+
+mod sibling {
+    use crate::compass::DirectionTable;
+    use rotate_enum::enum_table;
+
+    pub const LETTERS: DirectionTable<char> = enum_table!(crate::compass::Direction => char {
+        West: 'W',
+        North: 'N',
+        East: 'E',
+        South: 'S',
+    });
+}
+
+#[test]
+fn test_enum_table_path() {
+    assert_eq!(sibling::LETTERS.into_array(), ['N', 'E', 'S', 'W']);
+    assert_eq!(sibling::LETTERS[compass::Direction::West.next()], 'N');
+}
@@ -0,0 +1,35 @@
+use rotate_enum::EnumTools;
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(shift, iter, names)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(count)]
+enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+#[test]
+fn test_tools() {
+    assert_eq!(Direction::Up.next(), Some(Direction::Left));
+    assert_eq!(Direction::Up.prev(), None);
+    assert_eq!(
+        Direction::Down.iter().collect::<Vec<_>>(),
+        vec![Direction::Down, Direction::Right]
+    );
+    assert_eq!(Direction::COUNT, 4);
+    assert_eq!(Direction::Left.name(), "Left");
+    assert_eq!(Direction::VARIANT_NAMES, ["Up", "Left", "Down", "Right"]);
+    assert_eq!(Direction::VARIANT_NAMES.len(), Direction::COUNT);
+
+    assert_eq!(Axis::COUNT, 2);
+    assert_eq!(Axis::Horizontal.index(), 1);
+    assert_eq!(Axis::from_index(0), Some(Axis::Vertical));
+}
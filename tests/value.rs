@@ -0,0 +1,38 @@
+use rotate_enum::{enumeration, EnumTools};
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate)]
+enum Direction {
+    #[value(90.0)]
+    Up,
+    #[value(180.0)]
+    Left,
+    #[value(-90.0)]
+    Down,
+    #[value(0.0)]
+    Right,
+}
+
+#[enumeration(count)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Speed {
+    #[value(0.5)]
+    Slow,
+    #[value(1.0)]
+    Normal,
+    #[value(2.0 * 1.0)]
+    Fast,
+}
+
+#[test]
+fn test_value() {
+    assert_eq!(Direction::Up.value(), 90.0);
+    assert_eq!(Direction::Down.value(), -90.0);
+    assert_eq!(Direction::Up.next().value(), 180.0);
+    assert_eq!(Direction::from_value(0.0), Some(Direction::Right));
+    assert_eq!(Direction::from_value(-90.0), Some(Direction::Down));
+    assert_eq!(Direction::from_value(45.0), None);
+
+    assert_eq!(Speed::Fast.value(), 2.0);
+    assert_eq!(Speed::from_value(0.5), Some(Speed::Slow));
+}
@@ -0,0 +1,19 @@
+use rotate_enum::EnumTools;
+
+#[derive(EnumTools, PartialEq, Clone, Copy, Debug)]
+#[enum_tools(rotate)]
+enum Loot {
+    #[weight(10)]
+    Coin,
+    #[weight(0)]
+    Nothing,
+    #[weight(3)]
+    Potion,
+}
+
+#[test]
+fn test_weight() {
+    assert_eq!(Loot::Coin.weight(), 10);
+    assert_eq!(Loot::Nothing.weight(), 0);
+    assert_eq!(Loot::Coin.next().next().weight(), 3);
+}